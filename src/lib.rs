@@ -5,28 +5,89 @@ pub use pure_rust_locales::Locale;
 use pure_rust_locales::locale_match;
 #[cfg(feature = "rust_decimal")]
 use rust_decimal::Decimal;
+use std::borrow::Cow;
 use std::fmt;
 use std::fmt::{Debug, Display, Error as FmtError, Formatter, LowerExp, Write as FmtWrite};
+use std::marker::PhantomData;
 use std::str::{from_utf8_unchecked, FromStr};
 
 /// Symbols for number formatting.
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub struct NumberSymbols {
     /// Decimal separator
-    pub decimal_sep: char,
+    pub decimal_sep: ShortSym,
     /// Decimal grouping
     pub decimal_grp: Option<char>,
+    /// Grouping sizes (primary, secondary). The primary group is next
+    /// to the decimal separator, all further groups use the secondary size.
+    /// A secondary size of 0 means no further grouping.
+    ///
+    /// The sizes are only used for patterns with a single grouping
+    /// separator and a group of the primary size like "#,##0". Other
+    /// patterns and all patterns with None here are used as written.
+    pub grouping: Option<(u8, u8)>,
+    /// Grouping in the fraction.
+    pub fraction_grp: Option<char>,
+    /// Minimum number of digits in the leftmost group. Below that
+    /// there is no grouping at all. With 2 a number 1234 is not grouped
+    /// but 12,345 is.
+    pub min_grouping: u8,
     /// Minus sign
-    pub negative_sym: char,
+    pub negative_sym: ShortSym,
     /// Plus sign
-    pub positive_sym: char,
+    pub positive_sym: ShortSym,
     /// Exponent
     pub exponent_upper_sym: char,
     /// Exponent
     pub exponent_lower_sym: char,
     /// Currency
     pub currency_sym: CurrencySym,
-    // todo: zero-digit, infinity, nan
+    /// Text marker for positive numbers. Empty by default, only
+    /// negative numbers are marked. Set to e.g. CR to mark both.
+    pub positive_txt: ShortSym,
+    /// Text marker for negative numbers. E.g. DB
+    pub negative_txt: ShortSym,
+    /// Percent
+    pub percent_sym: char,
+    /// Per mille
+    pub permille_sym: char,
+    /// Zero digit of the digit system. The digits are the ten
+    /// consecutive chars starting with this one, e.g. '٠' for
+    /// Arabic-Indic or '०' for Devanagari digits.
+    ///
+    /// The locale constructors always use '0'. glibc formats numbers
+    /// with ascii digits for all locales and has no digit system in
+    /// LC_NUMERIC, so native digits must be set manually.
+    pub zero_digit: char,
+    /// Not a number
+    pub nan_sym: ShortSym,
+    /// Infinity
+    pub infinity_sym: ShortSym,
+    /// Currency symbol precedes a positive value.
+    pub p_cs_precedes: bool,
+    /// Currency symbol precedes a negative value.
+    pub n_cs_precedes: bool,
+    /// Space between currency symbol, sign and a positive value.
+    ///
+    /// * 0 - no space.
+    /// * 1 - if currency symbol and sign are adjacent, a space separates
+    ///   them from the value, otherwise the currency symbol from the value.
+    /// * 2 - if currency symbol and sign are adjacent, a space separates
+    ///   them, otherwise the sign from the value.
+    pub p_sep_by_space: u8,
+    /// Space between currency symbol, sign and a negative value.
+    /// See p_sep_by_space.
+    pub n_sep_by_space: u8,
+    /// Position of the sign for a positive value.
+    ///
+    /// * 0 - parentheses around value and currency symbol.
+    /// * 1 - sign before value and currency symbol.
+    /// * 2 - sign after value and currency symbol.
+    /// * 3 - sign immediately before the currency symbol.
+    /// * 4 - sign immediately after the currency symbol.
+    pub p_sign_posn: u8,
+    /// Position of the sign for a negative value. See p_sign_posn.
+    pub n_sign_posn: u8,
 }
 
 impl Default for NumberSymbols {
@@ -36,15 +97,60 @@ impl Default for NumberSymbols {
 }
 
 impl NumberSymbols {
+    /// Is there a grouping separator with x digits to its right.
+    /// Without grouping sizes groups of 3 are used.
+    fn is_grouping_pos(&self, x: u32) -> bool {
+        let (primary, secondary) = self.grouping.unwrap_or((3, 3));
+        let (primary, secondary) = (primary as u32, secondary as u32);
+        x == primary || (secondary > 0 && x > primary && (x - primary) % secondary == 0)
+    }
+
+    /// Maps an ascii digit to the digit system.
+    #[inline]
+    fn map_digit(&self, c: u8) -> char {
+        if self.zero_digit == '0' || !c.is_ascii_digit() {
+            c as char
+        } else {
+            char::from_u32(self.zero_digit as u32 + (c - b'0') as u32).unwrap_or(c as char)
+        }
+    }
+
+    /// Maps a digit of the digit system back to ascii.
+    #[inline]
+    fn ascii_digit(&self, c: char) -> char {
+        let d = (c as u32).wrapping_sub(self.zero_digit as u32);
+        if d < 10 {
+            (b'0' + d as u8) as char
+        } else {
+            c
+        }
+    }
+
     pub const fn new() -> Self {
         Self {
-            decimal_sep: '.',
+            decimal_sep: ShortSym::new("."),
             decimal_grp: Some(','),
-            negative_sym: '-',
-            positive_sym: ' ',
+            grouping: None,
+            fraction_grp: Some(' '),
+            min_grouping: 1,
+            negative_sym: ShortSym::new("-"),
+            positive_sym: ShortSym::new(" "),
             exponent_upper_sym: 'E',
             exponent_lower_sym: 'e',
             currency_sym: CurrencySym::new("$"),
+            positive_txt: ShortSym::new(""),
+            negative_txt: ShortSym::new("DB"),
+            percent_sym: '%',
+            permille_sym: '‰',
+            zero_digit: '0',
+            nan_sym: ShortSym::new("NaN"),
+            infinity_sym: ShortSym::new("∞"),
+            p_cs_precedes: true,
+            n_cs_precedes: true,
+            p_sep_by_space: 0,
+            n_sep_by_space: 0,
+            p_sign_posn: 1,
+            n_sign_posn: 1,
         }
     }
 
@@ -53,17 +159,40 @@ impl NumberSymbols {
     /// This function sets
     /// * decimal_sep to LC_NUMERIC::DECIMAL_POINT,
     /// * decimal_grp to LC_NUMERIC::THOUSANDS_SEP
+    /// * grouping to LC_NUMERIC::GROUPING. glibc has the lakh/crore
+    ///   grouping of hi_IN only in LC_MONETARY::MON_GROUPING, so a plain
+    ///   `[3]` is replaced by a monetary grouping with a different
+    ///   secondary size.
     ///
     /// Fills the rest with defaults.
     pub fn numeric(locale: Locale) -> Self {
         Self {
-            decimal_sep: first_or(locale_match!(locale => LC_NUMERIC::DECIMAL_POINT), '.'),
+            decimal_sep: sym_or(locale_match!(locale => LC_NUMERIC::DECIMAL_POINT), "."),
             decimal_grp: first_opt(locale_match!(locale => LC_NUMERIC::THOUSANDS_SEP)),
-            negative_sym: '-',
-            positive_sym: ' ',
+            grouping: numeric_grouping(
+                locale_match!(locale => LC_NUMERIC::GROUPING),
+                locale_match!(locale => LC_MONETARY::MON_GROUPING),
+            ),
+            fraction_grp: Some(' '),
+            min_grouping: 1,
+            negative_sym: ShortSym::new("-"),
+            positive_sym: ShortSym::new(" "),
             exponent_upper_sym: 'E',
             exponent_lower_sym: 'e',
             currency_sym: CurrencySym::new("$"),
+            positive_txt: ShortSym::new(""),
+            negative_txt: ShortSym::new("DB"),
+            percent_sym: '%',
+            permille_sym: '‰',
+            zero_digit: '0',
+            nan_sym: ShortSym::new("NaN"),
+            infinity_sym: ShortSym::new("∞"),
+            p_cs_precedes: true,
+            n_cs_precedes: true,
+            p_sep_by_space: 0,
+            n_sep_by_space: 0,
+            p_sign_posn: 1,
+            n_sign_posn: 1,
         }
     }
 
@@ -72,20 +201,52 @@ impl NumberSymbols {
     /// This function sets
     /// * decimal_sep to LC_MONETARY::MON_DECIMAL_POINT,
     /// * decimal_grp to LC_MONETARY::MON_THOUSANDS_SEP
+    /// * grouping to LC_MONETARY::MON_GROUPING
     /// * negative_sym to LC_MONETARY::NEGATIVE_SIGN
     /// * positive_sym to LC_MONETARY::POSITIVE_SIGN
     /// * currency_sym to LC_MONETARY::CURRENCY_SYMBOL
+    /// * p_cs_precedes, n_cs_precedes, p_sep_by_space, n_sep_by_space,
+    ///   p_sign_posn and n_sign_posn to the LC_MONETARY fields.
     ///
     /// Fills the rest with defaults.
     pub fn monetary(locale: Locale) -> Self {
         Self {
-            decimal_sep: first_or(locale_match!(locale => LC_MONETARY::MON_DECIMAL_POINT), '.'),
+            decimal_sep: sym_or(locale_match!(locale => LC_MONETARY::MON_DECIMAL_POINT), "."),
             decimal_grp: first_opt(locale_match!(locale => LC_MONETARY::MON_THOUSANDS_SEP)),
-            negative_sym: first_or(locale_match!(locale => LC_MONETARY::NEGATIVE_SIGN), '-'),
-            positive_sym: first_or(locale_match!(locale => LC_MONETARY::POSITIVE_SIGN), ' '),
+            grouping: grouping_opt(locale_match!(locale => LC_MONETARY::MON_GROUPING)),
+            fraction_grp: Some(' '),
+            min_grouping: 1,
+            negative_sym: sym_or(locale_match!(locale => LC_MONETARY::NEGATIVE_SIGN), "-"),
+            positive_sym: sym_or(locale_match!(locale => LC_MONETARY::POSITIVE_SIGN), " "),
             exponent_upper_sym: 'E',
             exponent_lower_sym: 'e',
             currency_sym: CurrencySym::new(locale_match!(locale => LC_MONETARY::CURRENCY_SYMBOL)),
+            positive_txt: ShortSym::new(""),
+            negative_txt: ShortSym::new("DB"),
+            percent_sym: '%',
+            permille_sym: '‰',
+            zero_digit: '0',
+            nan_sym: ShortSym::new("NaN"),
+            infinity_sym: ShortSym::new("∞"),
+            p_cs_precedes: posix_flag(locale_match!(locale => LC_MONETARY::P_CS_PRECEDES), true),
+            n_cs_precedes: posix_flag(locale_match!(locale => LC_MONETARY::N_CS_PRECEDES), true),
+            p_sep_by_space: posix_u8(locale_match!(locale => LC_MONETARY::P_SEP_BY_SPACE), 0),
+            n_sep_by_space: posix_u8(locale_match!(locale => LC_MONETARY::N_SEP_BY_SPACE), 0),
+            p_sign_posn: posix_u8(locale_match!(locale => LC_MONETARY::P_SIGN_POSN), 1),
+            n_sign_posn: posix_u8(locale_match!(locale => LC_MONETARY::N_SIGN_POSN), 1),
+        }
+    }
+
+    /// Same as `monetary` but for a foreign currency.
+    ///
+    /// This function sets
+    /// * currency_sym to the display variant of the currency.
+    ///
+    /// Everything else is taken from the locale.
+    pub fn monetary_with(locale: Locale, currency: &Currency, display: CurrencyDisplay) -> Self {
+        Self {
+            currency_sym: CurrencySym::new(currency.display(display)),
+            ..Self::monetary(locale)
         }
     }
 
@@ -94,28 +255,76 @@ impl NumberSymbols {
     /// This function sets
     /// * decimal_sep to LC_MONETARY::MON_DECIMAL_POINT,
     /// * decimal_grp to LC_MONETARY::MON_THOUSANDS_SEP
+    /// * grouping to LC_MONETARY::MON_GROUPING
     /// * negative_sym to LC_MONETARY::NEGATIVE_SIGN
     /// * positive_sym to LC_MONETARY::POSITIVE_SIGN
     /// * currency_sym to LC_MONETARY::INT_CURR_SYMBOL
+    /// * p_cs_precedes, n_cs_precedes, p_sep_by_space, n_sep_by_space,
+    ///   p_sign_posn and n_sign_posn to the LC_MONETARY::INT_* fields
+    ///   or the regular ones.
     ///
     /// Fills the rest with defaults.
     pub fn int_monetary(locale: Locale) -> Self {
         Self {
-            decimal_sep: first_or(locale_match!(locale => LC_MONETARY::MON_DECIMAL_POINT), '.'),
+            decimal_sep: sym_or(locale_match!(locale => LC_MONETARY::MON_DECIMAL_POINT), "."),
             decimal_grp: first_opt(locale_match!(locale => LC_MONETARY::MON_THOUSANDS_SEP)),
-            negative_sym: first_or(locale_match!(locale => LC_MONETARY::NEGATIVE_SIGN), '-'),
-            positive_sym: first_or(locale_match!(locale => LC_MONETARY::POSITIVE_SIGN), ' '),
+            grouping: grouping_opt(locale_match!(locale => LC_MONETARY::MON_GROUPING)),
+            fraction_grp: Some(' '),
+            min_grouping: 1,
+            negative_sym: sym_or(locale_match!(locale => LC_MONETARY::NEGATIVE_SIGN), "-"),
+            positive_sym: sym_or(locale_match!(locale => LC_MONETARY::POSITIVE_SIGN), " "),
             exponent_upper_sym: 'E',
             exponent_lower_sym: 'e',
             currency_sym: CurrencySym::new(locale_match!(locale => LC_MONETARY::INT_CURR_SYMBOL)),
+            positive_txt: ShortSym::new(""),
+            negative_txt: ShortSym::new("DB"),
+            percent_sym: '%',
+            permille_sym: '‰',
+            zero_digit: '0',
+            nan_sym: ShortSym::new("NaN"),
+            infinity_sym: ShortSym::new("∞"),
+            p_cs_precedes: posix_flag(
+                locale_match!(locale => LC_MONETARY::INT_P_CS_PRECEDES)
+                    .unwrap_or(locale_match!(locale => LC_MONETARY::P_CS_PRECEDES)),
+                true,
+            ),
+            n_cs_precedes: posix_flag(
+                locale_match!(locale => LC_MONETARY::INT_N_CS_PRECEDES)
+                    .unwrap_or(locale_match!(locale => LC_MONETARY::N_CS_PRECEDES)),
+                true,
+            ),
+            p_sep_by_space: posix_u8(
+                locale_match!(locale => LC_MONETARY::INT_P_SEP_BY_SPACE)
+                    .unwrap_or(locale_match!(locale => LC_MONETARY::P_SEP_BY_SPACE)),
+                0,
+            ),
+            n_sep_by_space: posix_u8(
+                locale_match!(locale => LC_MONETARY::INT_N_SEP_BY_SPACE)
+                    .unwrap_or(locale_match!(locale => LC_MONETARY::N_SEP_BY_SPACE)),
+                0,
+            ),
+            p_sign_posn: posix_u8(
+                locale_match!(locale => LC_MONETARY::INT_P_SIGN_POSN)
+                    .unwrap_or(locale_match!(locale => LC_MONETARY::P_SIGN_POSN)),
+                1,
+            ),
+            n_sign_posn: posix_u8(
+                locale_match!(locale => LC_MONETARY::INT_N_SIGN_POSN)
+                    .unwrap_or(locale_match!(locale => LC_MONETARY::N_SIGN_POSN)),
+                1,
+            ),
         }
     }
 }
 
-// first char or default
+// symbol or default
 #[inline]
-fn first_or(s: &str, default: char) -> char {
-    s.chars().next().unwrap_or(default)
+fn sym_or(s: &str, default: &str) -> ShortSym {
+    if s.is_empty() {
+        ShortSym::new(default)
+    } else {
+        ShortSym::new(s)
+    }
 }
 
 // first char or default
@@ -124,12 +333,1747 @@ fn first_opt(s: &str) -> Option<char> {
     s.chars().next()
 }
 
+// posix flag, -1 is undefined
+#[inline]
+fn posix_flag(v: i64, default: bool) -> bool {
+    match v {
+        0 => false,
+        1 => true,
+        _ => default,
+    }
+}
+
+// posix value, -1 is undefined
+#[inline]
+fn posix_u8(v: i64, default: u8) -> u8 {
+    match v {
+        0..=4 => v as u8,
+        _ => default,
+    }
+}
+
+// glibc grouping as (primary, secondary).
+//
+// The last size repeats, 0 repeats the size before and -1 or CHAR_MAX
+// stops grouping. Lists with more than two different sizes like
+// [2, 2, 2, 3] don't fit and give None.
+fn grouping_opt(grp: &[i64]) -> Option<(u8, u8)> {
+    let size = |v: i64| match v {
+        1..=126 => Some(v as u8),
+        _ => None,
+    };
+    let stop = |v: i64| !(0..127).contains(&v);
+
+    let (&p, rest) = grp.split_first()?;
+    let p = size(p)?;
+    match rest {
+        [] | [0, ..] => Some((p, p)),
+        [s, ..] if stop(*s) => Some((p, 0)),
+        [s, tail @ ..] => {
+            let s = size(*s)?;
+            if tail.iter().all(|v| *v == s as i64 || *v == 0) {
+                Some((p, s))
+            } else {
+                None
+            }
+        }
+    }
+}
+
+// numeric grouping, falls back to the monetary grouping if that has
+// a different secondary size.
+fn numeric_grouping(grp: &[i64], mon_grp: &[i64]) -> Option<(u8, u8)> {
+    match (grouping_opt(grp), grouping_opt(mon_grp)) {
+        (Some((p, s)), Some((mp, ms))) if grp.len() == 1 && p == mp && s != ms && ms != 0 => {
+            Some((mp, ms))
+        }
+        (v, _) => v,
+    }
+}
+
+// value pattern for the given fraction digits, -1 is undefined.
+// a single integer digit, the format grows with the number.
+fn currency_pattern(frac_digits: i64) -> String {
+    let frac_digits = if frac_digits < 0 { 2 } else { frac_digits };
+    let mut pattern = String::from("0");
+    if frac_digits > 0 {
+        pattern.push('.');
+        for _ in 0..frac_digits {
+            pattern.push('0');
+        }
+    }
+    pattern
+}
+
+/// ISO 4217 currency.
+///
+/// [Currency::all] has the active codes of ISO 4217 list one, including
+/// the funds and the X codes for metals and units of account. Withdrawn
+/// codes are not listed.
+///
+/// Symbols and names are the english ones from CLDR, there are no
+/// translations. For other languages set the currency_sym of the
+/// [NumberSymbols] directly.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Currency {
+    /// Alphabetic code.
+    pub code: &'static str,
+    /// Numeric code.
+    pub numeric: u16,
+    /// Number of digits of the minor unit. ISO 4217 has none for the
+    /// X codes like XAU or XXX, these use 2 like CLDR does.
+    pub minor_units: u8,
+    /// Rounding increment for cash in minor units, 5 rounds to 0.05.
+    /// 0 if cash uses the minor unit too.
+    pub cash_increment: u32,
+    /// Symbol.
+    pub symbol: &'static str,
+    /// Narrow symbol. Might be ambiguous, "$" for all the dollars.
+    pub narrow_symbol: &'static str,
+    /// Display name.
+    pub name: &'static str,
+}
+
+/// Which variant of the currency is shown.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum CurrencyDisplay {
+    /// ISO code.
+    Code,
+    /// Symbol.
+    #[default]
+    Symbol,
+    /// Narrow symbol.
+    NarrowSymbol,
+    /// Display name.
+    Name,
+}
+
+impl Currency {
+    /// Finds the currency for the ISO code. Ignores case.
+    pub fn find(code: &str) -> Option<&'static Currency> {
+        CURRENCIES
+            .iter()
+            .find(|v| v.code.eq_ignore_ascii_case(code))
+    }
+
+    /// All known currencies, sorted by code.
+    pub fn all() -> &'static [Currency] {
+        CURRENCIES
+    }
+
+    /// Text for the display variant.
+    pub fn display(&self, display: CurrencyDisplay) -> &'static str {
+        match display {
+            CurrencyDisplay::Code => self.code,
+            CurrencyDisplay::Symbol => self.symbol,
+            CurrencyDisplay::NarrowSymbol => self.narrow_symbol,
+            CurrencyDisplay::Name => self.name,
+        }
+    }
+}
+
+static CURRENCIES: &[Currency] = &[
+    Currency {
+        code: "AED",
+        numeric: 784,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "AED",
+        narrow_symbol: "AED",
+        name: "UAE Dirham",
+    },
+    Currency {
+        code: "AFN",
+        numeric: 971,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "AFN",
+        narrow_symbol: "؋",
+        name: "Afghan Afghani",
+    },
+    Currency {
+        code: "ALL",
+        numeric: 8,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "ALL",
+        narrow_symbol: "ALL",
+        name: "Albanian Lek",
+    },
+    Currency {
+        code: "AMD",
+        numeric: 51,
+        minor_units: 2,
+        cash_increment: 100,
+        symbol: "AMD",
+        narrow_symbol: "֏",
+        name: "Armenian Dram",
+    },
+    Currency {
+        code: "AOA",
+        numeric: 973,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "AOA",
+        narrow_symbol: "Kz",
+        name: "Angolan Kwanza",
+    },
+    Currency {
+        code: "ARS",
+        numeric: 32,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "ARS",
+        narrow_symbol: "$",
+        name: "Argentine Peso",
+    },
+    Currency {
+        code: "AUD",
+        numeric: 36,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "A$",
+        narrow_symbol: "$",
+        name: "Australian Dollar",
+    },
+    Currency {
+        code: "AWG",
+        numeric: 533,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "AWG",
+        narrow_symbol: "AWG",
+        name: "Aruban Florin",
+    },
+    Currency {
+        code: "AZN",
+        numeric: 944,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "AZN",
+        narrow_symbol: "₼",
+        name: "Azerbaijani Manat",
+    },
+    Currency {
+        code: "BAM",
+        numeric: 977,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "BAM",
+        narrow_symbol: "KM",
+        name: "Bosnia-Herzegovina Convertible Mark",
+    },
+    Currency {
+        code: "BBD",
+        numeric: 52,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "BBD",
+        narrow_symbol: "$",
+        name: "Barbadian Dollar",
+    },
+    Currency {
+        code: "BDT",
+        numeric: 50,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "BDT",
+        narrow_symbol: "৳",
+        name: "Bangladeshi Taka",
+    },
+    Currency {
+        code: "BHD",
+        numeric: 48,
+        minor_units: 3,
+        cash_increment: 0,
+        symbol: "BHD",
+        narrow_symbol: "BHD",
+        name: "Bahraini Dinar",
+    },
+    Currency {
+        code: "BIF",
+        numeric: 108,
+        minor_units: 0,
+        cash_increment: 0,
+        symbol: "BIF",
+        narrow_symbol: "BIF",
+        name: "Burundian Franc",
+    },
+    Currency {
+        code: "BMD",
+        numeric: 60,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "BMD",
+        narrow_symbol: "$",
+        name: "Bermudan Dollar",
+    },
+    Currency {
+        code: "BND",
+        numeric: 96,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "BND",
+        narrow_symbol: "$",
+        name: "Brunei Dollar",
+    },
+    Currency {
+        code: "BOB",
+        numeric: 68,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "BOB",
+        narrow_symbol: "Bs",
+        name: "Bolivian Boliviano",
+    },
+    Currency {
+        code: "BOV",
+        numeric: 984,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "BOV",
+        narrow_symbol: "BOV",
+        name: "Bolivian Mvdol",
+    },
+    Currency {
+        code: "BRL",
+        numeric: 986,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "R$",
+        narrow_symbol: "R$",
+        name: "Brazilian Real",
+    },
+    Currency {
+        code: "BSD",
+        numeric: 44,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "BSD",
+        narrow_symbol: "$",
+        name: "Bahamian Dollar",
+    },
+    Currency {
+        code: "BTN",
+        numeric: 64,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "BTN",
+        narrow_symbol: "BTN",
+        name: "Bhutanese Ngultrum",
+    },
+    Currency {
+        code: "BWP",
+        numeric: 72,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "BWP",
+        narrow_symbol: "P",
+        name: "Botswanan Pula",
+    },
+    Currency {
+        code: "BYN",
+        numeric: 933,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "BYN",
+        narrow_symbol: "BYN",
+        name: "Belarusian Ruble",
+    },
+    Currency {
+        code: "BZD",
+        numeric: 84,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "BZD",
+        narrow_symbol: "$",
+        name: "Belize Dollar",
+    },
+    Currency {
+        code: "CAD",
+        numeric: 124,
+        minor_units: 2,
+        cash_increment: 5,
+        symbol: "CA$",
+        narrow_symbol: "$",
+        name: "Canadian Dollar",
+    },
+    Currency {
+        code: "CDF",
+        numeric: 976,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "CDF",
+        narrow_symbol: "CDF",
+        name: "Congolese Franc",
+    },
+    Currency {
+        code: "CHE",
+        numeric: 947,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "CHE",
+        narrow_symbol: "CHE",
+        name: "WIR Euro",
+    },
+    Currency {
+        code: "CHF",
+        numeric: 756,
+        minor_units: 2,
+        cash_increment: 5,
+        symbol: "CHF",
+        narrow_symbol: "CHF",
+        name: "Swiss Franc",
+    },
+    Currency {
+        code: "CHW",
+        numeric: 948,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "CHW",
+        narrow_symbol: "CHW",
+        name: "WIR Franc",
+    },
+    Currency {
+        code: "CLF",
+        numeric: 990,
+        minor_units: 4,
+        cash_increment: 0,
+        symbol: "CLF",
+        narrow_symbol: "CLF",
+        name: "Chilean Unit of Account (UF)",
+    },
+    Currency {
+        code: "CLP",
+        numeric: 152,
+        minor_units: 0,
+        cash_increment: 0,
+        symbol: "CLP",
+        narrow_symbol: "$",
+        name: "Chilean Peso",
+    },
+    Currency {
+        code: "CNY",
+        numeric: 156,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "CN¥",
+        narrow_symbol: "¥",
+        name: "Chinese Yuan",
+    },
+    Currency {
+        code: "COP",
+        numeric: 170,
+        minor_units: 2,
+        cash_increment: 100,
+        symbol: "COP",
+        narrow_symbol: "$",
+        name: "Colombian Peso",
+    },
+    Currency {
+        code: "COU",
+        numeric: 970,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "COU",
+        narrow_symbol: "COU",
+        name: "Colombian Real Value Unit",
+    },
+    Currency {
+        code: "CRC",
+        numeric: 188,
+        minor_units: 2,
+        cash_increment: 100,
+        symbol: "CRC",
+        narrow_symbol: "₡",
+        name: "Costa Rican Colón",
+    },
+    Currency {
+        code: "CUP",
+        numeric: 192,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "CUP",
+        narrow_symbol: "$",
+        name: "Cuban Peso",
+    },
+    Currency {
+        code: "CVE",
+        numeric: 132,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "CVE",
+        narrow_symbol: "CVE",
+        name: "Cape Verdean Escudo",
+    },
+    Currency {
+        code: "CZK",
+        numeric: 203,
+        minor_units: 2,
+        cash_increment: 100,
+        symbol: "CZK",
+        narrow_symbol: "Kč",
+        name: "Czech Koruna",
+    },
+    Currency {
+        code: "DJF",
+        numeric: 262,
+        minor_units: 0,
+        cash_increment: 0,
+        symbol: "DJF",
+        narrow_symbol: "DJF",
+        name: "Djiboutian Franc",
+    },
+    Currency {
+        code: "DKK",
+        numeric: 208,
+        minor_units: 2,
+        cash_increment: 50,
+        symbol: "DKK",
+        narrow_symbol: "kr",
+        name: "Danish Krone",
+    },
+    Currency {
+        code: "DOP",
+        numeric: 214,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "DOP",
+        narrow_symbol: "$",
+        name: "Dominican Peso",
+    },
+    Currency {
+        code: "DZD",
+        numeric: 12,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "DZD",
+        narrow_symbol: "DZD",
+        name: "Algerian Dinar",
+    },
+    Currency {
+        code: "EGP",
+        numeric: 818,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "EGP",
+        narrow_symbol: "E£",
+        name: "Egyptian Pound",
+    },
+    Currency {
+        code: "ERN",
+        numeric: 232,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "ERN",
+        narrow_symbol: "ERN",
+        name: "Eritrean Nakfa",
+    },
+    Currency {
+        code: "ETB",
+        numeric: 230,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "ETB",
+        narrow_symbol: "ETB",
+        name: "Ethiopian Birr",
+    },
+    Currency {
+        code: "EUR",
+        numeric: 978,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "€",
+        narrow_symbol: "€",
+        name: "Euro",
+    },
+    Currency {
+        code: "FJD",
+        numeric: 242,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "FJD",
+        narrow_symbol: "$",
+        name: "Fijian Dollar",
+    },
+    Currency {
+        code: "FKP",
+        numeric: 238,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "FKP",
+        narrow_symbol: "£",
+        name: "Falkland Islands Pound",
+    },
+    Currency {
+        code: "GBP",
+        numeric: 826,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "£",
+        narrow_symbol: "£",
+        name: "British Pound",
+    },
+    Currency {
+        code: "GEL",
+        numeric: 981,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "GEL",
+        narrow_symbol: "₾",
+        name: "Georgian Lari",
+    },
+    Currency {
+        code: "GHS",
+        numeric: 936,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "GHS",
+        narrow_symbol: "GH₵",
+        name: "Ghanaian Cedi",
+    },
+    Currency {
+        code: "GIP",
+        numeric: 292,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "GIP",
+        narrow_symbol: "£",
+        name: "Gibraltar Pound",
+    },
+    Currency {
+        code: "GMD",
+        numeric: 270,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "GMD",
+        narrow_symbol: "GMD",
+        name: "Gambian Dalasi",
+    },
+    Currency {
+        code: "GNF",
+        numeric: 324,
+        minor_units: 0,
+        cash_increment: 0,
+        symbol: "GNF",
+        narrow_symbol: "FG",
+        name: "Guinean Franc",
+    },
+    Currency {
+        code: "GTQ",
+        numeric: 320,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "GTQ",
+        narrow_symbol: "Q",
+        name: "Guatemalan Quetzal",
+    },
+    Currency {
+        code: "GYD",
+        numeric: 328,
+        minor_units: 2,
+        cash_increment: 100,
+        symbol: "GYD",
+        narrow_symbol: "$",
+        name: "Guyanaese Dollar",
+    },
+    Currency {
+        code: "HKD",
+        numeric: 344,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "HK$",
+        narrow_symbol: "$",
+        name: "Hong Kong Dollar",
+    },
+    Currency {
+        code: "HNL",
+        numeric: 340,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "HNL",
+        narrow_symbol: "L",
+        name: "Honduran Lempira",
+    },
+    Currency {
+        code: "HTG",
+        numeric: 332,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "HTG",
+        narrow_symbol: "HTG",
+        name: "Haitian Gourde",
+    },
+    Currency {
+        code: "HUF",
+        numeric: 348,
+        minor_units: 2,
+        cash_increment: 100,
+        symbol: "HUF",
+        narrow_symbol: "Ft",
+        name: "Hungarian Forint",
+    },
+    Currency {
+        code: "IDR",
+        numeric: 360,
+        minor_units: 2,
+        cash_increment: 100,
+        symbol: "IDR",
+        narrow_symbol: "Rp",
+        name: "Indonesian Rupiah",
+    },
+    Currency {
+        code: "ILS",
+        numeric: 376,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "₪",
+        narrow_symbol: "₪",
+        name: "Israeli New Shekel",
+    },
+    Currency {
+        code: "INR",
+        numeric: 356,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "₹",
+        narrow_symbol: "₹",
+        name: "Indian Rupee",
+    },
+    Currency {
+        code: "IQD",
+        numeric: 368,
+        minor_units: 3,
+        cash_increment: 0,
+        symbol: "IQD",
+        narrow_symbol: "IQD",
+        name: "Iraqi Dinar",
+    },
+    Currency {
+        code: "IRR",
+        numeric: 364,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "IRR",
+        narrow_symbol: "IRR",
+        name: "Iranian Rial",
+    },
+    Currency {
+        code: "ISK",
+        numeric: 352,
+        minor_units: 0,
+        cash_increment: 0,
+        symbol: "ISK",
+        narrow_symbol: "kr",
+        name: "Icelandic Króna",
+    },
+    Currency {
+        code: "JMD",
+        numeric: 388,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "JMD",
+        narrow_symbol: "$",
+        name: "Jamaican Dollar",
+    },
+    Currency {
+        code: "JOD",
+        numeric: 400,
+        minor_units: 3,
+        cash_increment: 0,
+        symbol: "JOD",
+        narrow_symbol: "JOD",
+        name: "Jordanian Dinar",
+    },
+    Currency {
+        code: "JPY",
+        numeric: 392,
+        minor_units: 0,
+        cash_increment: 0,
+        symbol: "¥",
+        narrow_symbol: "¥",
+        name: "Japanese Yen",
+    },
+    Currency {
+        code: "KES",
+        numeric: 404,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "KES",
+        narrow_symbol: "KES",
+        name: "Kenyan Shilling",
+    },
+    Currency {
+        code: "KGS",
+        numeric: 417,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "KGS",
+        narrow_symbol: "KGS",
+        name: "Kyrgystani Som",
+    },
+    Currency {
+        code: "KHR",
+        numeric: 116,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "KHR",
+        narrow_symbol: "៛",
+        name: "Cambodian Riel",
+    },
+    Currency {
+        code: "KMF",
+        numeric: 174,
+        minor_units: 0,
+        cash_increment: 0,
+        symbol: "KMF",
+        narrow_symbol: "CF",
+        name: "Comorian Franc",
+    },
+    Currency {
+        code: "KPW",
+        numeric: 408,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "KPW",
+        narrow_symbol: "₩",
+        name: "North Korean Won",
+    },
+    Currency {
+        code: "KRW",
+        numeric: 410,
+        minor_units: 0,
+        cash_increment: 0,
+        symbol: "₩",
+        narrow_symbol: "₩",
+        name: "South Korean Won",
+    },
+    Currency {
+        code: "KWD",
+        numeric: 414,
+        minor_units: 3,
+        cash_increment: 0,
+        symbol: "KWD",
+        narrow_symbol: "KWD",
+        name: "Kuwaiti Dinar",
+    },
+    Currency {
+        code: "KYD",
+        numeric: 136,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "KYD",
+        narrow_symbol: "$",
+        name: "Cayman Islands Dollar",
+    },
+    Currency {
+        code: "KZT",
+        numeric: 398,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "KZT",
+        narrow_symbol: "₸",
+        name: "Kazakhstani Tenge",
+    },
+    Currency {
+        code: "LAK",
+        numeric: 418,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "LAK",
+        narrow_symbol: "₭",
+        name: "Laotian Kip",
+    },
+    Currency {
+        code: "LBP",
+        numeric: 422,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "LBP",
+        narrow_symbol: "L£",
+        name: "Lebanese Pound",
+    },
+    Currency {
+        code: "LKR",
+        numeric: 144,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "LKR",
+        narrow_symbol: "Rs",
+        name: "Sri Lankan Rupee",
+    },
+    Currency {
+        code: "LRD",
+        numeric: 430,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "LRD",
+        narrow_symbol: "$",
+        name: "Liberian Dollar",
+    },
+    Currency {
+        code: "LSL",
+        numeric: 426,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "LSL",
+        narrow_symbol: "LSL",
+        name: "Lesotho Loti",
+    },
+    Currency {
+        code: "LYD",
+        numeric: 434,
+        minor_units: 3,
+        cash_increment: 0,
+        symbol: "LYD",
+        narrow_symbol: "LYD",
+        name: "Libyan Dinar",
+    },
+    Currency {
+        code: "MAD",
+        numeric: 504,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "MAD",
+        narrow_symbol: "MAD",
+        name: "Moroccan Dirham",
+    },
+    Currency {
+        code: "MDL",
+        numeric: 498,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "MDL",
+        narrow_symbol: "MDL",
+        name: "Moldovan Leu",
+    },
+    Currency {
+        code: "MGA",
+        numeric: 969,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "MGA",
+        narrow_symbol: "Ar",
+        name: "Malagasy Ariary",
+    },
+    Currency {
+        code: "MKD",
+        numeric: 807,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "MKD",
+        narrow_symbol: "MKD",
+        name: "Macedonian Denar",
+    },
+    Currency {
+        code: "MMK",
+        numeric: 104,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "MMK",
+        narrow_symbol: "K",
+        name: "Myanmar Kyat",
+    },
+    Currency {
+        code: "MNT",
+        numeric: 496,
+        minor_units: 2,
+        cash_increment: 100,
+        symbol: "MNT",
+        narrow_symbol: "₮",
+        name: "Mongolian Tugrik",
+    },
+    Currency {
+        code: "MOP",
+        numeric: 446,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "MOP",
+        narrow_symbol: "MOP",
+        name: "Macanese Pataca",
+    },
+    Currency {
+        code: "MRU",
+        numeric: 929,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "MRU",
+        narrow_symbol: "MRU",
+        name: "Mauritanian Ouguiya",
+    },
+    Currency {
+        code: "MUR",
+        numeric: 480,
+        minor_units: 2,
+        cash_increment: 100,
+        symbol: "MUR",
+        narrow_symbol: "Rs",
+        name: "Mauritian Rupee",
+    },
+    Currency {
+        code: "MVR",
+        numeric: 462,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "MVR",
+        narrow_symbol: "MVR",
+        name: "Maldivian Rufiyaa",
+    },
+    Currency {
+        code: "MWK",
+        numeric: 454,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "MWK",
+        narrow_symbol: "MWK",
+        name: "Malawian Kwacha",
+    },
+    Currency {
+        code: "MXN",
+        numeric: 484,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "MX$",
+        narrow_symbol: "$",
+        name: "Mexican Peso",
+    },
+    Currency {
+        code: "MXV",
+        numeric: 979,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "MXV",
+        narrow_symbol: "MXV",
+        name: "Mexican Investment Unit",
+    },
+    Currency {
+        code: "MYR",
+        numeric: 458,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "MYR",
+        narrow_symbol: "RM",
+        name: "Malaysian Ringgit",
+    },
+    Currency {
+        code: "MZN",
+        numeric: 943,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "MZN",
+        narrow_symbol: "MZN",
+        name: "Mozambican Metical",
+    },
+    Currency {
+        code: "NAD",
+        numeric: 516,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "NAD",
+        narrow_symbol: "$",
+        name: "Namibian Dollar",
+    },
+    Currency {
+        code: "NGN",
+        numeric: 566,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "NGN",
+        narrow_symbol: "₦",
+        name: "Nigerian Naira",
+    },
+    Currency {
+        code: "NIO",
+        numeric: 558,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "NIO",
+        narrow_symbol: "C$",
+        name: "Nicaraguan Córdoba",
+    },
+    Currency {
+        code: "NOK",
+        numeric: 578,
+        minor_units: 2,
+        cash_increment: 100,
+        symbol: "NOK",
+        narrow_symbol: "kr",
+        name: "Norwegian Krone",
+    },
+    Currency {
+        code: "NPR",
+        numeric: 524,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "NPR",
+        narrow_symbol: "Rs",
+        name: "Nepalese Rupee",
+    },
+    Currency {
+        code: "NZD",
+        numeric: 554,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "NZ$",
+        narrow_symbol: "$",
+        name: "New Zealand Dollar",
+    },
+    Currency {
+        code: "OMR",
+        numeric: 512,
+        minor_units: 3,
+        cash_increment: 0,
+        symbol: "OMR",
+        narrow_symbol: "OMR",
+        name: "Omani Rial",
+    },
+    Currency {
+        code: "PAB",
+        numeric: 590,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "PAB",
+        narrow_symbol: "PAB",
+        name: "Panamanian Balboa",
+    },
+    Currency {
+        code: "PEN",
+        numeric: 604,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "PEN",
+        narrow_symbol: "PEN",
+        name: "Peruvian Sol",
+    },
+    Currency {
+        code: "PGK",
+        numeric: 598,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "PGK",
+        narrow_symbol: "PGK",
+        name: "Papua New Guinean Kina",
+    },
+    Currency {
+        code: "PHP",
+        numeric: 608,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "₱",
+        narrow_symbol: "₱",
+        name: "Philippine Peso",
+    },
+    Currency {
+        code: "PKR",
+        numeric: 586,
+        minor_units: 2,
+        cash_increment: 100,
+        symbol: "PKR",
+        narrow_symbol: "Rs",
+        name: "Pakistani Rupee",
+    },
+    Currency {
+        code: "PLN",
+        numeric: 985,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "PLN",
+        narrow_symbol: "zł",
+        name: "Polish Zloty",
+    },
+    Currency {
+        code: "PYG",
+        numeric: 600,
+        minor_units: 0,
+        cash_increment: 0,
+        symbol: "PYG",
+        narrow_symbol: "₲",
+        name: "Paraguayan Guarani",
+    },
+    Currency {
+        code: "QAR",
+        numeric: 634,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "QAR",
+        narrow_symbol: "QAR",
+        name: "Qatari Riyal",
+    },
+    Currency {
+        code: "RON",
+        numeric: 946,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "RON",
+        narrow_symbol: "lei",
+        name: "Romanian Leu",
+    },
+    Currency {
+        code: "RSD",
+        numeric: 941,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "RSD",
+        narrow_symbol: "RSD",
+        name: "Serbian Dinar",
+    },
+    Currency {
+        code: "RUB",
+        numeric: 643,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "RUB",
+        narrow_symbol: "₽",
+        name: "Russian Ruble",
+    },
+    Currency {
+        code: "RWF",
+        numeric: 646,
+        minor_units: 0,
+        cash_increment: 0,
+        symbol: "RWF",
+        narrow_symbol: "RF",
+        name: "Rwandan Franc",
+    },
+    Currency {
+        code: "SAR",
+        numeric: 682,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "SAR",
+        narrow_symbol: "SAR",
+        name: "Saudi Riyal",
+    },
+    Currency {
+        code: "SBD",
+        numeric: 90,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "SBD",
+        narrow_symbol: "$",
+        name: "Solomon Islands Dollar",
+    },
+    Currency {
+        code: "SCR",
+        numeric: 690,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "SCR",
+        narrow_symbol: "SCR",
+        name: "Seychellois Rupee",
+    },
+    Currency {
+        code: "SDG",
+        numeric: 938,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "SDG",
+        narrow_symbol: "SDG",
+        name: "Sudanese Pound",
+    },
+    Currency {
+        code: "SEK",
+        numeric: 752,
+        minor_units: 2,
+        cash_increment: 100,
+        symbol: "SEK",
+        narrow_symbol: "kr",
+        name: "Swedish Krona",
+    },
+    Currency {
+        code: "SGD",
+        numeric: 702,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "SGD",
+        narrow_symbol: "$",
+        name: "Singapore Dollar",
+    },
+    Currency {
+        code: "SHP",
+        numeric: 654,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "SHP",
+        narrow_symbol: "£",
+        name: "St. Helena Pound",
+    },
+    Currency {
+        code: "SLE",
+        numeric: 925,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "SLE",
+        narrow_symbol: "SLE",
+        name: "Sierra Leonean Leone",
+    },
+    Currency {
+        code: "SOS",
+        numeric: 706,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "SOS",
+        narrow_symbol: "SOS",
+        name: "Somali Shilling",
+    },
+    Currency {
+        code: "SRD",
+        numeric: 968,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "SRD",
+        narrow_symbol: "$",
+        name: "Surinamese Dollar",
+    },
+    Currency {
+        code: "SSP",
+        numeric: 728,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "SSP",
+        narrow_symbol: "£",
+        name: "South Sudanese Pound",
+    },
+    Currency {
+        code: "STN",
+        numeric: 930,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "STN",
+        narrow_symbol: "Db",
+        name: "São Tomé & Príncipe Dobra",
+    },
+    Currency {
+        code: "SVC",
+        numeric: 222,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "SVC",
+        narrow_symbol: "SVC",
+        name: "Salvadoran Colón",
+    },
+    Currency {
+        code: "SYP",
+        numeric: 760,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "SYP",
+        narrow_symbol: "£",
+        name: "Syrian Pound",
+    },
+    Currency {
+        code: "SZL",
+        numeric: 748,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "SZL",
+        narrow_symbol: "SZL",
+        name: "Swazi Lilangeni",
+    },
+    Currency {
+        code: "THB",
+        numeric: 764,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "THB",
+        narrow_symbol: "฿",
+        name: "Thai Baht",
+    },
+    Currency {
+        code: "TJS",
+        numeric: 972,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "TJS",
+        narrow_symbol: "TJS",
+        name: "Tajikistani Somoni",
+    },
+    Currency {
+        code: "TMT",
+        numeric: 934,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "TMT",
+        narrow_symbol: "TMT",
+        name: "Turkmenistani Manat",
+    },
+    Currency {
+        code: "TND",
+        numeric: 788,
+        minor_units: 3,
+        cash_increment: 0,
+        symbol: "TND",
+        narrow_symbol: "TND",
+        name: "Tunisian Dinar",
+    },
+    Currency {
+        code: "TOP",
+        numeric: 776,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "TOP",
+        narrow_symbol: "T$",
+        name: "Tongan Paʻanga",
+    },
+    Currency {
+        code: "TRY",
+        numeric: 949,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "TRY",
+        narrow_symbol: "₺",
+        name: "Turkish Lira",
+    },
+    Currency {
+        code: "TTD",
+        numeric: 780,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "TTD",
+        narrow_symbol: "$",
+        name: "Trinidad & Tobago Dollar",
+    },
+    Currency {
+        code: "TWD",
+        numeric: 901,
+        minor_units: 2,
+        cash_increment: 100,
+        symbol: "NT$",
+        narrow_symbol: "$",
+        name: "New Taiwan Dollar",
+    },
+    Currency {
+        code: "TZS",
+        numeric: 834,
+        minor_units: 2,
+        cash_increment: 100,
+        symbol: "TZS",
+        narrow_symbol: "TZS",
+        name: "Tanzanian Shilling",
+    },
+    Currency {
+        code: "UAH",
+        numeric: 980,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "UAH",
+        narrow_symbol: "₴",
+        name: "Ukrainian Hryvnia",
+    },
+    Currency {
+        code: "UGX",
+        numeric: 800,
+        minor_units: 0,
+        cash_increment: 0,
+        symbol: "UGX",
+        narrow_symbol: "UGX",
+        name: "Ugandan Shilling",
+    },
+    Currency {
+        code: "USD",
+        numeric: 840,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "$",
+        narrow_symbol: "$",
+        name: "US Dollar",
+    },
+    Currency {
+        code: "USN",
+        numeric: 997,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "USN",
+        narrow_symbol: "USN",
+        name: "US Dollar (Next day)",
+    },
+    Currency {
+        code: "UYI",
+        numeric: 940,
+        minor_units: 0,
+        cash_increment: 0,
+        symbol: "UYI",
+        narrow_symbol: "UYI",
+        name: "Uruguayan Peso (Indexed Units)",
+    },
+    Currency {
+        code: "UYU",
+        numeric: 858,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "UYU",
+        narrow_symbol: "$",
+        name: "Uruguayan Peso",
+    },
+    Currency {
+        code: "UYW",
+        numeric: 927,
+        minor_units: 4,
+        cash_increment: 0,
+        symbol: "UYW",
+        narrow_symbol: "UYW",
+        name: "Uruguayan Nominal Wage Index Unit",
+    },
+    Currency {
+        code: "UZS",
+        numeric: 860,
+        minor_units: 2,
+        cash_increment: 100,
+        symbol: "UZS",
+        narrow_symbol: "UZS",
+        name: "Uzbekistani Som",
+    },
+    Currency {
+        code: "VED",
+        numeric: 926,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "VED",
+        narrow_symbol: "VED",
+        name: "Bolívar Soberano",
+    },
+    Currency {
+        code: "VES",
+        numeric: 928,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "VES",
+        narrow_symbol: "Bs",
+        name: "Venezuelan Bolívar",
+    },
+    Currency {
+        code: "VND",
+        numeric: 704,
+        minor_units: 0,
+        cash_increment: 0,
+        symbol: "₫",
+        narrow_symbol: "₫",
+        name: "Vietnamese Dong",
+    },
+    Currency {
+        code: "VUV",
+        numeric: 548,
+        minor_units: 0,
+        cash_increment: 0,
+        symbol: "VUV",
+        narrow_symbol: "VUV",
+        name: "Vanuatu Vatu",
+    },
+    Currency {
+        code: "WST",
+        numeric: 882,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "WST",
+        narrow_symbol: "WST",
+        name: "Samoan Tala",
+    },
+    Currency {
+        code: "XAF",
+        numeric: 950,
+        minor_units: 0,
+        cash_increment: 0,
+        symbol: "FCFA",
+        narrow_symbol: "FCFA",
+        name: "Central African CFA Franc",
+    },
+    Currency {
+        code: "XAG",
+        numeric: 961,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "XAG",
+        narrow_symbol: "XAG",
+        name: "Silver",
+    },
+    Currency {
+        code: "XAU",
+        numeric: 959,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "XAU",
+        narrow_symbol: "XAU",
+        name: "Gold",
+    },
+    Currency {
+        code: "XBA",
+        numeric: 955,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "XBA",
+        narrow_symbol: "XBA",
+        name: "European Composite Unit",
+    },
+    Currency {
+        code: "XBB",
+        numeric: 956,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "XBB",
+        narrow_symbol: "XBB",
+        name: "European Monetary Unit",
+    },
+    Currency {
+        code: "XBC",
+        numeric: 957,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "XBC",
+        narrow_symbol: "XBC",
+        name: "European Unit of Account (XBC)",
+    },
+    Currency {
+        code: "XBD",
+        numeric: 958,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "XBD",
+        narrow_symbol: "XBD",
+        name: "European Unit of Account (XBD)",
+    },
+    Currency {
+        code: "XCD",
+        numeric: 951,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "EC$",
+        narrow_symbol: "$",
+        name: "East Caribbean Dollar",
+    },
+    Currency {
+        code: "XCG",
+        numeric: 532,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "XCG",
+        narrow_symbol: "XCG",
+        name: "Caribbean Guilder",
+    },
+    Currency {
+        code: "XDR",
+        numeric: 960,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "XDR",
+        narrow_symbol: "XDR",
+        name: "Special Drawing Rights",
+    },
+    Currency {
+        code: "XOF",
+        numeric: 952,
+        minor_units: 0,
+        cash_increment: 0,
+        symbol: "F CFA",
+        narrow_symbol: "F CFA",
+        name: "West African CFA Franc",
+    },
+    Currency {
+        code: "XPD",
+        numeric: 964,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "XPD",
+        narrow_symbol: "XPD",
+        name: "Palladium",
+    },
+    Currency {
+        code: "XPF",
+        numeric: 953,
+        minor_units: 0,
+        cash_increment: 0,
+        symbol: "CFPF",
+        narrow_symbol: "CFPF",
+        name: "CFP Franc",
+    },
+    Currency {
+        code: "XPT",
+        numeric: 962,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "XPT",
+        narrow_symbol: "XPT",
+        name: "Platinum",
+    },
+    Currency {
+        code: "XSU",
+        numeric: 994,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "XSU",
+        narrow_symbol: "XSU",
+        name: "Sucre",
+    },
+    Currency {
+        code: "XTS",
+        numeric: 963,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "XTS",
+        narrow_symbol: "XTS",
+        name: "Testing Currency Code",
+    },
+    Currency {
+        code: "XUA",
+        numeric: 965,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "XUA",
+        narrow_symbol: "XUA",
+        name: "ADB Unit of Account",
+    },
+    Currency {
+        code: "XXX",
+        numeric: 999,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "¤",
+        narrow_symbol: "¤",
+        name: "Unknown Currency",
+    },
+    Currency {
+        code: "YER",
+        numeric: 886,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "YER",
+        narrow_symbol: "YER",
+        name: "Yemeni Rial",
+    },
+    Currency {
+        code: "ZAR",
+        numeric: 710,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "ZAR",
+        narrow_symbol: "R",
+        name: "South African Rand",
+    },
+    Currency {
+        code: "ZMW",
+        numeric: 967,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "ZMW",
+        narrow_symbol: "ZK",
+        name: "Zambian Kwacha",
+    },
+    Currency {
+        code: "ZWG",
+        numeric: 924,
+        minor_units: 2,
+        cash_increment: 0,
+        symbol: "ZWG",
+        narrow_symbol: "ZWG",
+        name: "Zimbabwean Gold",
+    },
+];
+
 /// Currency symbol.
 /// Const constructable short inline string.
 #[derive(PartialEq, Eq, Clone, Copy)]
 pub struct CurrencySym {
     len: u8,
-    sym: [u8; 16],
+    sym: [u8; CurrencySym::CAPACITY],
 }
 
 impl Debug for CurrencySym {
@@ -142,22 +2086,26 @@ impl Debug for CurrencySym {
 }
 
 impl CurrencySym {
+    /// Maximum length in bytes. Fits all names of [Currency::all].
+    pub const CAPACITY: usize = 39;
+
     /// New currency symbol.
+    ///
+    /// A symbol longer than [CurrencySym::CAPACITY] bytes is truncated
+    /// at the last char that fits.
     pub const fn new(src: &str) -> Self {
-        let mut sym = [0u8; 16];
-
-        let src = src.as_bytes();
-        let src_len = src.len();
-
-        let mut i = 0;
-        while i < src_len && i < 16 {
-            sym[i] = src[i];
-            i += 1;
-        }
+        let (len, sym) = copy_sym(src);
+        CurrencySym { len, sym }
+    }
 
-        CurrencySym {
-            len: src_len as u8,
-            sym,
+    /// New currency symbol.
+    ///
+    /// Fails if the symbol is longer than [CurrencySym::CAPACITY] bytes.
+    pub const fn try_new(src: &str) -> Result<Self, NumberFmtError> {
+        if src.len() > CurrencySym::CAPACITY {
+            Err(NumberFmtError::SymbolTooLong)
+        } else {
+            Ok(CurrencySym::new(src))
         }
     }
 
@@ -191,6 +2139,103 @@ impl<'a> From<&'a str> for CurrencySym {
     }
 }
 
+/// Sign, separator or marker symbol.
+/// Const constructable short inline string, smaller than [CurrencySym].
+///
+/// Used for the signs, the decimal separator, the text markers and
+/// NaN and infinity.
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub struct ShortSym {
+    len: u8,
+    sym: [u8; ShortSym::CAPACITY],
+}
+
+impl Debug for ShortSym {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ShortSym")
+            .field("len", &self.len)
+            .field("sym", &self.as_str())
+            .finish()
+    }
+}
+
+impl ShortSym {
+    /// Maximum length in bytes.
+    pub const CAPACITY: usize = 15;
+
+    /// New symbol.
+    ///
+    /// A symbol longer than [ShortSym::CAPACITY] bytes is truncated
+    /// at the last char that fits.
+    pub const fn new(src: &str) -> Self {
+        let (len, sym) = copy_sym(src);
+        ShortSym { len, sym }
+    }
+
+    /// New symbol.
+    ///
+    /// Fails if the symbol is longer than [ShortSym::CAPACITY] bytes.
+    pub const fn try_new(src: &str) -> Result<Self, NumberFmtError> {
+        if src.len() > ShortSym::CAPACITY {
+            Err(NumberFmtError::SymbolTooLong)
+        } else {
+            Ok(ShortSym::new(src))
+        }
+    }
+
+    /// Convert back to &str
+    pub fn as_str(&self) -> &str {
+        // Safety:
+        // Copied from &str and never modified.
+        unsafe { from_utf8_unchecked(&self.sym[..self.len as usize]) }
+    }
+
+    /// Symbol len.
+    pub const fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    /// Symbol empty.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl Display for ShortSym {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for ShortSym {
+    fn from(value: &'a str) -> Self {
+        ShortSym::new(value)
+    }
+}
+
+// copies at most N bytes, truncated at a char boundary.
+const fn copy_sym<const N: usize>(src: &str) -> (u8, [u8; N]) {
+    let mut sym = [0u8; N];
+
+    let src = src.as_bytes();
+    let mut len = src.len();
+    if len > N {
+        len = N;
+        // don't cut an utf8 sequence.
+        while len > 0 && src[len] & 0xC0 == 0x80 {
+            len -= 1;
+        }
+    }
+
+    let mut i = 0;
+    while i < len {
+        sym[i] = src[i];
+        i += 1;
+    }
+
+    (len as u8, sym)
+}
+
 /// Number mode.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Mode {
@@ -208,25 +2253,42 @@ enum Mode {
 /// Numeric has an extra flag, to mark if a sign at this position is possible.
 /// Next to a grouping separator there can be no sign, it will be at the position
 /// of the grouping separator.
+///
+/// The mask chars "1" to "8" are parsed as Digit0 with the digit in an extra
+/// field. They define the rounding increment.
 #[allow(variant_size_differences)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Token {
-    /// Mask char "0". Digit or 0
-    Digit0(Mode, u32),
-    /// Mask char "9". Digit or space
-    Digit(Mode, u32),
+    /// Mask char "0". Digit or 0. Mask chars "1" to "8" have their digit
+    /// for the rounding increment.
+    Digit0(Mode, u32, u8),
+    /// Mask char "9". Digit or space. Mask chars "1" to "8" in the integer
+    /// without a "0" to the left have their digit for the rounding increment.
+    Digit(Mode, u32, u8),
     /// Mask char "#". Digit or sign or space
     Numeric(Mode, u32, bool),
     /// Mask char "-". Integer sign.
     SignInt,
     /// Mask char "+". Integer sign.
     PlusInt,
+    /// Mask char "S". Integer sign as text marker. Shows positive_txt or negative_txt.
+    SignTxt,
+    /// Mask char "@". Significant digits. The whole run of "@" and "#" is
+    /// collapsed to one token, which outputs the complete number with
+    /// variable length. Has a flag if the sign is shown here.
+    Significant(bool),
+    /// Mask char "(". Integer sign. Shows "(" for negative and space for positive.
+    ParenOpen,
+    /// Mask char ")". Closes the "(". Shows ")" for negative and space for positive.
+    ParenClose,
     /// Mask char ".". Decimal separator.
     DecimalSep,
     /// Mask char ":". Decimal separator, always displayed.
     DecimalSepAlways,
     /// Mask char ",". Grouping separator.
     GroupingSep(u32, bool),
+    /// Mask char ",". Grouping separator in the fraction.
+    FractionGrp(u32),
     /// Mask char "E". Exponent separator.
     ExponentUpper,
     /// Mask char "e". Exponent separator.
@@ -237,10 +2299,52 @@ enum Token {
     PlusExp,
     /// Mask char "$". Currency. Variable length.
     Currency,
+    /// Mask char "%". Percent. Multiplies by 100.
+    Percent,
+    /// Mask char "‰". Per mille. Multiplies by 1000.
+    PerMille,
     /// Other separator char to output literally. May be escaped with '\\'.
     Separator(char),
 }
 
+/// Rounding modes.
+///
+/// Rounding is done on the decimal digits of the number, not on the binary
+/// representation of a float. The float 2.675 is rounded as 2.675 and
+/// not as 2.67499999999999982236431605997495353221893310546875.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Round to nearest, ties away from zero.
+    HalfUp,
+    /// Round to nearest, ties to the even neighbour.
+    HalfEven,
+    /// Round to nearest, ties toward zero.
+    HalfDown,
+    /// Round toward positive infinity.
+    Ceiling,
+    /// Round toward negative infinity.
+    Floor,
+    /// Round toward zero. Truncates.
+    TowardZero,
+    /// Round away from zero.
+    AwayFromZero,
+}
+
+/// Handling of numbers with more integer digits than the pattern allows,
+/// or a negative number without a place for the sign.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Overflow {
+    /// Fails with [NumberFmtError::FmtLenInt] or [NumberFmtError::FmtNoSign].
+    #[default]
+    Error,
+    /// Expands the pattern by repeating the leftmost digit and the
+    /// grouping. "#,##0" becomes "#,###,##0". A "#" is added for the
+    /// sign if necessary, "0.00" shows -12345 as "-12345.00".
+    Expand,
+    /// Fills the complete output with this char, like '#' or '*'.
+    Fill(char),
+}
+
 /// Holds the pattern for the number format and some additional data.
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct NumberFormat {
@@ -262,11 +2366,49 @@ pub struct NumberFormat {
     has_frac_0: bool,
     /// The required precision for this format. Is used for the underlying std-format.
     len_frac: u8,
+    /// Decimal scale. The number is multiplied by 10^scale before formatting.
+    scale: i32,
+    /// Number of digits of the minor unit. Part of the scale.
+    minor_units: u32,
+    /// Rounding mode. If None the rounding is done by the std-format.
+    rounding: Option<RoundingMode>,
+    /// Minimum number of significant digits.
+    min_sig: u32,
+    /// Maximum number of significant digits. Zero if the pattern has
+    /// no significant digits.
+    max_sig: u32,
+    /// Group the integer part of the significant digits.
+    sig_grp: bool,
+    /// The grouping separators are placed by the grouping sizes of
+    /// the symbols.
+    sym_grp: bool,
+
+    /// Rounding increment as (increment, scale). The number is rounded
+    /// to a multiple of increment * 10^-scale.
+    increment: Option<(u32, u32)>,
+    /// General notation. Exponent range (min, max) that uses the
+    /// fixed layout instead of the exponent.
+    general: Option<(i32, i32)>,
+    /// Engineering notation. The exponent is a multiple of 3.
+    engineering: bool,
+    /// Numbers too large for the pattern.
+    overflow: Overflow,
 
     /// Tokens.
     tok: Vec<Token>,
     /// Symbols.
     sym: NumberSymbols,
+
+    /// This is the section for negative numbers. The sign is implied by
+    /// the section and needs no place in the pattern. "#" digits don't
+    /// show the sign.
+    implied_sign: bool,
+    /// Section for negative numbers.
+    neg: Option<Box<NumberFormat>>,
+    /// Section for zero.
+    zero: Option<Box<NumberFormat>>,
+    /// Section for NaN.
+    nan: Option<Box<NumberFormat>>,
 }
 
 /// Errors
@@ -302,6 +2444,20 @@ pub enum NumberFmtError {
     ParseInvalidCurrency,
     /// Invalid separator when parsing.
     ParseInvalidSeparator,
+    /// Too many sections in the pattern.
+    ParseInvalidSection,
+    /// Significant digits mixed with other digits in the pattern.
+    ParseInvalidSignificant,
+    /// Extra characters after the end of the pattern when parsing.
+    ParseTrailing,
+    /// Symbol is too long for [CurrencySym] or [ShortSym].
+    SymbolTooLong,
+    /// Non-zero fraction when parsing an integer.
+    ParseFraction,
+    /// Number is out of range for the integer type when parsing.
+    ParseOverflow,
+    /// The format shows no currency.
+    FmtNoCurrency,
 }
 
 impl std::error::Error for NumberFmtError {}
@@ -320,21 +2476,51 @@ impl From<FmtError> for NumberFmtError {
 
 impl Display for NumberFormat {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.fmt_tok(f)?;
+        for section in [&self.neg, &self.zero, &self.nan].into_iter().flatten() {
+            write!(f, ";")?;
+            section.fmt_tok(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl NumberFormat {
+    fn fmt_tok(&self, f: &mut Formatter<'_>) -> fmt::Result {
         for t in &self.tok {
             match t {
-                Token::Digit0(_, _) => write!(f, "0")?,
-                Token::Digit(_, _) => write!(f, "9")?,
+                Token::Digit0(_, _, digit) => write!(f, "{}", digit)?,
+                Token::Digit(_, _, 0) => write!(f, "9")?,
+                Token::Digit(_, _, digit) => write!(f, "{}", digit)?,
                 Token::Numeric(_, _, _) => write!(f, "#")?,
                 Token::SignInt => write!(f, "-")?,
                 Token::PlusInt => write!(f, "-")?,
+                Token::SignTxt => write!(f, "S")?,
+                Token::Significant(_) => {
+                    if self.sig_grp {
+                        write!(f, "#,##")?;
+                    }
+                    for i in 0..self.max_sig {
+                        if i < self.min_sig {
+                            write!(f, "@")?;
+                        } else {
+                            write!(f, "#")?;
+                        }
+                    }
+                }
+                Token::ParenOpen => write!(f, "(")?,
+                Token::ParenClose => write!(f, ")")?,
                 Token::DecimalSep => write!(f, ".")?,
                 Token::DecimalSepAlways => write!(f, ":")?,
                 Token::GroupingSep(_, _) => write!(f, ",")?,
+                Token::FractionGrp(_) => write!(f, ",")?,
                 Token::ExponentUpper => write!(f, "E")?,
                 Token::ExponentLower => write!(f, "e")?,
                 Token::SignExp => write!(f, "-")?,
                 Token::PlusExp => write!(f, "+")?,
                 Token::Currency => write!(f, "$")?,
+                Token::Percent => write!(f, "%")?,
+                Token::PerMille => write!(f, "‰")?,
                 Token::Separator(c) => {
                     if *c < '\u{0100}' {
                         write!(f, "\\ ")?;
@@ -351,13 +2537,306 @@ impl NumberFormat {
     /// New format from pattern.
     pub fn new<S: AsRef<str>>(pattern: S) -> Result<Self, NumberFmtError> {
         let tok = Self::parse_tokens(pattern.as_ref())?;
-        Self::news_tok(tok, NumberSymbols::new())
+        Self::news_sections(tok, NumberSymbols::new())
     }
 
     /// New format from pattern + symbols
     pub fn news<S: AsRef<str>>(pattern: S, sym: NumberSymbols) -> Result<Self, NumberFmtError> {
         let tok = Self::parse_tokens(pattern.as_ref())?;
-        Self::news_tok(tok, sym)
+        Self::news_sections(tok, sym)
+    }
+
+    /// New monetary format from a pattern for the value only.
+    ///
+    /// The currency symbol and the sign are placed according to the
+    /// cs_precedes, sep_by_space and sign_posn of the symbols. The
+    /// pattern can have neither, and only one section.
+    ///
+    /// With sign_posn 0 negative values are shown in parentheses.
+    ///
+    /// The space at the end of LC_MONETARY::INT_CURR_SYMBOL is removed,
+    /// the separator is placed by sep_by_space.
+    pub fn monetary<S: AsRef<str>>(
+        pattern: S,
+        mut sym: NumberSymbols,
+    ) -> Result<Self, NumberFmtError> {
+        sym.currency_sym = CurrencySym::new(sym.currency_sym.as_str().trim_end());
+
+        let mut tok = Self::parse_tokens(pattern.as_ref())?;
+        if tok.len() != 1 {
+            return Err(NumberFmtError::ParseInvalidSection);
+        }
+        let value = tok.remove(0);
+        for t in &value {
+            match t {
+                Token::SignInt
+                | Token::PlusInt
+                | Token::SignTxt
+                | Token::ParenOpen
+                | Token::ParenClose => return Err(NumberFmtError::ParseInvalidSign),
+                Token::Currency => return Err(NumberFmtError::ParseInvalidCurrency),
+                _ => {}
+            }
+        }
+
+        let pos = Self::monetary_tok(&value, &sym, false);
+        let neg = Self::monetary_tok(&value, &sym, true);
+        let mut format = Self::news_sections(vec![pos, neg], sym)?;
+        // the sign is placed by sign_posn, it can float with the digits.
+        if let Some(neg) = &mut format.neg {
+            neg.implied_sign = false;
+        }
+        Ok(format)
+    }
+
+    /// New monetary format for the locale.
+    ///
+    /// Uses the symbols of `NumberSymbols::monetary` and the number of
+    /// fraction digits in LC_MONETARY::FRAC_DIGITS. There is no padding,
+    /// the integer part grows with the number and is grouped by the
+    /// grouping sizes of the symbols.
+    pub fn currency(locale: Locale) -> Result<Self, NumberFmtError> {
+        Self::monetary(
+            currency_pattern(locale_match!(locale => LC_MONETARY::FRAC_DIGITS)),
+            NumberSymbols::monetary(locale),
+        )
+        .map(Self::grow)
+    }
+
+    /// New monetary format for the locale with the international currency
+    /// symbol.
+    ///
+    /// Uses the symbols of `NumberSymbols::int_monetary` and the number of
+    /// fraction digits in LC_MONETARY::INT_FRAC_DIGITS. There is no padding
+    /// like with `currency`.
+    pub fn int_currency(locale: Locale) -> Result<Self, NumberFmtError> {
+        Self::monetary(
+            currency_pattern(locale_match!(locale => LC_MONETARY::INT_FRAC_DIGITS)),
+            NumberSymbols::int_monetary(locale),
+        )
+        .map(Self::grow)
+    }
+
+    /// New monetary format for a foreign currency in the locale.
+    ///
+    /// Uses the symbols of `NumberSymbols::monetary_with` and the minor
+    /// units of the currency. There is no padding like with `currency`.
+    ///
+    /// Cash rounding can be added with
+    /// `increment(currency.cash_increment, currency.minor_units as u32)`.
+    pub fn currency_with(
+        locale: Locale,
+        currency: &Currency,
+        display: CurrencyDisplay,
+    ) -> Result<Self, NumberFmtError> {
+        Self::monetary(
+            currency_pattern(currency.minor_units as i64),
+            NumberSymbols::monetary_with(locale, currency, display),
+        )
+        .map(Self::grow)
+    }
+
+    /// The format grows with the number, grouped by the grouping sizes
+    /// of the symbols.
+    fn grow(mut self) -> Self {
+        self.sections_mut(|v| {
+            v.overflow = Overflow::Expand;
+            v.sym_grp = true;
+        });
+        self
+    }
+
+    /// Same format for another currency. Replaces the currency symbol
+    /// and the number of fraction digits, everything else is kept.
+    ///
+    /// Fails with [NumberFmtError::FmtNoCurrency] if the format shows
+    /// no currency.
+    fn with_currency(
+        &self,
+        currency_sym: CurrencySym,
+        frac_digits: u32,
+    ) -> Result<NumberFormat, NumberFmtError> {
+        if !self.tok.iter().any(|t| matches!(t, Token::Currency)) {
+            return Err(NumberFmtError::FmtNoCurrency);
+        }
+        let mut format = self.clone();
+        format.set_currency(currency_sym, frac_digits)?;
+        for section in [&mut format.neg, &mut format.zero, &mut format.nan]
+            .into_iter()
+            .flatten()
+        {
+            section.set_currency(currency_sym, frac_digits)?;
+        }
+        Ok(format)
+    }
+
+    /// Replaces currency symbol and fraction digits of one section.
+    fn set_currency(
+        &mut self,
+        currency_sym: CurrencySym,
+        frac_digits: u32,
+    ) -> Result<(), NumberFmtError> {
+        self.sym.currency_sym = currency_sym;
+        // significant digits have no fixed fraction.
+        if self.max_sig > 0 {
+            return Ok(());
+        }
+
+        let mut tok = self.tok.clone();
+        tok.retain(|t| {
+            !matches!(
+                t,
+                Token::Digit0(Mode::Fraction, _, _)
+                    | Token::Digit(Mode::Fraction, _, _)
+                    | Token::Numeric(Mode::Fraction, _, _)
+                    | Token::FractionGrp(_)
+            )
+        });
+        let frac = (0..frac_digits).map(|_| Token::Digit0(Mode::Fraction, 0, 0));
+        if let Some(idx) = tok
+            .iter()
+            .position(|t| matches!(t, Token::DecimalSep | Token::DecimalSepAlways))
+        {
+            if frac_digits == 0 && tok[idx] == Token::DecimalSep {
+                tok.remove(idx);
+            } else {
+                tok.splice(idx + 1..idx + 1, frac);
+            }
+        } else if frac_digits > 0 {
+            if let Some(idx) = tok.iter().rposition(|t| {
+                matches!(
+                    t,
+                    Token::Digit0(Mode::Integer, _, _)
+                        | Token::Digit(Mode::Integer, _, _)
+                        | Token::Numeric(Mode::Integer, _, _)
+                )
+            }) {
+                tok.splice(
+                    idx + 1..idx + 1,
+                    std::iter::once(Token::DecimalSep).chain(frac),
+                );
+            }
+        }
+
+        let layout = Self::news_tok(tok, self.sym)?;
+        self.min_int_sign = layout.min_int_sign;
+        self.len_int = layout.len_int;
+        self.has_frac_0 = layout.has_frac_0;
+        self.len_frac = layout.len_frac;
+        self.tok = layout.tok;
+        Ok(())
+    }
+
+    /// Arranges value, currency and sign for one section.
+    ///
+    /// Layout uses 'S' for the sign, 'C' for the currency and 'V' for
+    /// the value.
+    fn monetary_tok(value: &[Token], sym: &NumberSymbols, negative: bool) -> Vec<Token> {
+        let (cs_precedes, sep_by_space, sign_posn) = if negative {
+            (sym.n_cs_precedes, sym.n_sep_by_space, sym.n_sign_posn)
+        } else {
+            (sym.p_cs_precedes, sym.p_sep_by_space, sym.p_sign_posn)
+        };
+        let paren = sign_posn == 0;
+        let has_sign = !paren && (negative || !sym.positive_sym.as_str().trim().is_empty());
+        let has_currency = !sym.currency_sym.as_str().is_empty();
+
+        let layout: &[u8] = match (sign_posn, cs_precedes) {
+            (2, true) => b"CVS",
+            (2, false) => b"VCS",
+            (3, false) => b"VSC",
+            (4, true) => b"CSV",
+            (4, false) => b"VCS",
+            (_, true) => b"SCV",
+            (_, false) => b"SVC",
+        };
+        let layout = layout
+            .iter()
+            .copied()
+            .filter(|v| (has_sign || *v != b'S') && (has_currency || *v != b'C'))
+            .collect::<Vec<_>>();
+        let sep_by_space = if !has_sign && sep_by_space == 2 {
+            1
+        } else {
+            sep_by_space
+        };
+
+        let idx_v = layout.iter().position(|v| *v == b'V').expect("value");
+        let idx_c = layout.iter().position(|v| *v == b'C');
+        let space_at = |i: usize| -> bool {
+            let (a, b) = (layout[i], layout[i + 1]);
+            match (sep_by_space, idx_c) {
+                (1, Some(idx_c)) => (a == b'V' && idx_c > idx_v) || (b == b'V' && idx_c < idx_v),
+                (2, _) => {
+                    if layout.len() == 3 && idx_v != 1 {
+                        // sign and currency are adjacent
+                        (a == b'S' && b == b'C') || (a == b'C' && b == b'S')
+                    } else {
+                        (a == b'S' && b == b'V') || (a == b'V' && b == b'S')
+                    }
+                }
+                _ => false,
+            }
+        };
+
+        let mut tok = Vec::new();
+        if paren && negative {
+            tok.push(Token::ParenOpen);
+        }
+        for (i, v) in layout.iter().enumerate() {
+            match v {
+                b'S' => {
+                    // a sign directly before the value can float with the digits.
+                    let floating = layout.get(i + 1) == Some(&b'V')
+                        && !space_at(i)
+                        && matches!(
+                            value.first(),
+                            Some(Token::Numeric(_, _, _) | Token::Significant(_))
+                        );
+                    if !floating {
+                        tok.push(Token::SignInt);
+                    }
+                }
+                b'C' => tok.push(Token::Currency),
+                _ => tok.extend_from_slice(value),
+            }
+            if i + 1 < layout.len() && space_at(i) {
+                tok.push(Token::Separator(' '));
+            }
+        }
+        if paren && negative {
+            tok.push(Token::ParenClose);
+        }
+        tok
+    }
+
+    /// New format from the token-arrays for each section.
+    ///
+    /// The sections are positive, negative, zero and NaN in this order.
+    /// Only the first one is required.
+    fn news_sections(
+        sections: Vec<Vec<Token>>,
+        sym: NumberSymbols,
+    ) -> Result<Self, NumberFmtError> {
+        let mut it = sections.into_iter();
+
+        let mut format = Self::news_tok(it.next().unwrap_or_default(), sym)?;
+        if let Some(tok) = it.next() {
+            let mut neg = Self::news_tok(tok, sym)?;
+            neg.implied_sign = true;
+            format.neg = Some(Box::new(neg));
+        }
+        if let Some(tok) = it.next() {
+            format.zero = Some(Box::new(Self::news_tok(tok, sym)?));
+        }
+        if let Some(tok) = it.next() {
+            format.nan = Some(Box::new(Self::news_tok(tok, sym)?));
+        }
+        if it.next().is_some() {
+            return Err(NumberFmtError::ParseInvalidSection);
+        }
+
+        Ok(format)
     }
 
     /// New format from token-array.
@@ -367,13 +2846,45 @@ impl NumberFormat {
         let mut has_dec_sep = false;
         let mut has_frac_0 = false;
         let mut has_int_sign = false;
+        let mut has_paren_open = false;
+        let mut has_paren_close = false;
         let mut min_int_sign = 0;
         let mut has_exp_sign = false;
         let mut min_exp_sign = 0;
         let mut len_frac = 0;
+        let mut scale = 0;
         let mut len_int = 0;
         let mut len_exp = 0;
 
+        let (min_sig, max_sig, sig_grp) = Self::collapse_significant(&mut pattern)?;
+
+        // grouping separators right after the last integer or fraction digit
+        // divide by 1000 each.
+        for mode in [Mode::Fraction, Mode::Integer] {
+            let Some(idx_last) = pattern.iter().rposition(|t| match t {
+                Token::Digit0(m, _, _) | Token::Digit(m, _, _) | Token::Numeric(m, _, _) => {
+                    *m == mode
+                }
+                _ => false,
+            }) else {
+                continue;
+            };
+            while matches!(
+                pattern.get(idx_last + 1),
+                Some(Token::GroupingSep(_, _) | Token::FractionGrp(_))
+            ) {
+                pattern.remove(idx_last + 1);
+                scale -= 3;
+            }
+        }
+
+        let increment = Self::pattern_increment(&pattern)?;
+
+        let sym_grp = Self::is_sym_grp(&pattern, &sym);
+        if sym_grp {
+            Self::relayout_grouping(&mut pattern, &sym);
+        }
+
         let mut idx_frac = 0;
         for t in pattern.iter_mut() {
             match t {
@@ -383,13 +2894,13 @@ impl NumberFormat {
                     }
                     has_dec_sep = true;
                 }
-                Token::Digit0(Mode::Fraction, x) => {
+                Token::Digit0(Mode::Fraction, x, _) => {
                     has_frac_0 = true;
                     len_frac += 1;
                     *x = idx_frac;
                     idx_frac += 1;
                 }
-                Token::Digit(Mode::Fraction, x) => {
+                Token::Digit(Mode::Fraction, x, _) => {
                     len_frac += 1;
                     *x = idx_frac;
                     idx_frac += 1;
@@ -400,6 +2911,9 @@ impl NumberFormat {
                     *sign = false;
                     idx_frac += 1;
                 }
+                Token::FractionGrp(x) => {
+                    *x = idx_frac;
+                }
 
                 Token::ExponentLower | Token::ExponentUpper => {
                     if has_exp {
@@ -420,6 +2934,25 @@ impl NumberFormat {
                     }
                     has_int_sign = true;
                 }
+                Token::SignTxt => {
+                    if has_int_sign {
+                        return Err(NumberFmtError::ParseInvalidSign);
+                    }
+                    has_int_sign = true;
+                }
+                Token::ParenOpen => {
+                    if has_int_sign {
+                        return Err(NumberFmtError::ParseInvalidSign);
+                    }
+                    has_int_sign = true;
+                    has_paren_open = true;
+                }
+                Token::ParenClose => {
+                    if !has_paren_open || has_paren_close {
+                        return Err(NumberFmtError::ParseInvalidSign);
+                    }
+                    has_paren_close = true;
+                }
                 Token::SignExp => {
                     if has_exp_sign {
                         return Err(NumberFmtError::ParseInvalidExpSign);
@@ -433,21 +2966,35 @@ impl NumberFormat {
                     has_exp_sign = true;
                 }
 
-                _ => {}
+                Token::Percent => {
+                    scale += 2;
+                }
+                Token::PerMille => {
+                    scale += 3;
+                }
+
+                _ => {}
             }
         }
+        if has_paren_open != has_paren_close {
+            return Err(NumberFmtError::ParseInvalidSign);
+        }
+        if max_sig > 0 && (has_dec_sep || len_frac > 0) {
+            return Err(NumberFmtError::ParseInvalidSignificant);
+        }
+
         let mut idx_int = 0;
         let mut idx_exp = 0;
         let mut was_grp = false;
         for t in pattern.iter_mut().rev() {
             match t {
-                Token::Digit0(Mode::Integer, x) => {
+                Token::Digit0(Mode::Integer, x, _) => {
                     len_int += 1;
                     min_int_sign = idx_int + 1;
                     *x = idx_int;
                     idx_int += 1;
                 }
-                Token::Digit(Mode::Integer, x) => {
+                Token::Digit(Mode::Integer, x, _) => {
                     len_int += 1;
                     min_int_sign = idx_int + 1;
                     *x = idx_int;
@@ -464,15 +3011,18 @@ impl NumberFormat {
                     *sign = !has_int_sign;
                     *x = idx_int;
                 }
+                Token::Significant(sign) => {
+                    *sign = !has_int_sign;
+                }
 
-                Token::Digit0(Mode::Exponent, x) => {
+                Token::Digit0(Mode::Exponent, x, _) => {
                     len_exp += 1;
                     has_exp_0 = true;
                     min_exp_sign = idx_exp + 1;
                     *x = idx_exp;
                     idx_exp += 1;
                 }
-                Token::Digit(Mode::Exponent, x) => {
+                Token::Digit(Mode::Exponent, x, _) => {
                     len_exp += 1;
                     min_exp_sign = idx_exp;
                     *x = idx_exp;
@@ -491,6 +3041,10 @@ impl NumberFormat {
             was_grp = matches!(t, Token::GroupingSep(_, _));
         }
 
+        if max_sig > 0 && len_int > 0 {
+            return Err(NumberFmtError::ParseInvalidSignificant);
+        }
+
         Ok(NumberFormat {
             min_int_sign,
             len_int,
@@ -500,16 +3054,173 @@ impl NumberFormat {
             has_exp_0,
             has_frac_0,
             len_frac,
+            scale,
+            minor_units: 0,
+            rounding: None,
+            min_sig,
+            max_sig,
+            sig_grp,
+            sym_grp,
+            increment,
+            general: None,
+            engineering: false,
+            overflow: Overflow::Error,
             tok: pattern,
             sym,
+            implied_sign: false,
+            neg: None,
+            zero: None,
+            nan: None,
         })
     }
 
+    /// The pattern has exactly one grouping separator, and the group
+    /// right of it has the primary size of the symbols. Other patterns
+    /// keep their grouping as written.
+    fn is_sym_grp(pattern: &[Token], sym: &NumberSymbols) -> bool {
+        let Some((primary, _)) = sym.grouping else {
+            return false;
+        };
+        let mut seps = pattern
+            .iter()
+            .enumerate()
+            .filter(|(_, t)| matches!(t, Token::GroupingSep(_, _)));
+        let (Some((idx, _)), None) = (seps.next(), seps.next()) else {
+            return false;
+        };
+        let size = pattern[idx + 1..]
+            .iter()
+            .take_while(|t| {
+                matches!(
+                    t,
+                    Token::Digit0(Mode::Integer, _, _)
+                        | Token::Digit(Mode::Integer, _, _)
+                        | Token::Numeric(Mode::Integer, _, _)
+                )
+            })
+            .count();
+        size == primary as usize
+    }
+
+    /// Places the grouping separators according to the grouping sizes
+    /// of the symbols.
+    fn relayout_grouping(pattern: &mut Vec<Token>, sym: &NumberSymbols) {
+        pattern.retain(|t| !matches!(t, Token::GroupingSep(_, _)));
+
+        let digits = pattern
+            .iter()
+            .enumerate()
+            .filter(|(_, t)| {
+                matches!(
+                    t,
+                    Token::Digit0(Mode::Integer, _, _)
+                        | Token::Digit(Mode::Integer, _, _)
+                        | Token::Numeric(Mode::Integer, _, _)
+                )
+            })
+            .map(|(idx, _)| idx)
+            .collect::<Vec<_>>();
+
+        // right to left keeps the indizes valid.
+        for (x, idx) in digits.iter().rev().enumerate().skip(1) {
+            if sym.is_grouping_pos(x as u32) {
+                pattern.insert(idx + 1, Token::GroupingSep(0, false));
+            }
+        }
+    }
+
+    /// Collapses the run of significant digits to one token.
+    ///
+    /// The run is an optional "#,##" for grouping, at least one "@" and
+    /// optional "#" for additional digits.
+    ///
+    /// Returns the minimum and maximum number of significant digits and
+    /// the grouping flag.
+    fn collapse_significant(pattern: &mut Vec<Token>) -> Result<(u32, u32, bool), NumberFmtError> {
+        let Some(first) = pattern
+            .iter()
+            .position(|t| matches!(t, Token::Significant(_)))
+        else {
+            return Ok((0, 0, false));
+        };
+
+        let mut start = first;
+        let mut sig_grp = false;
+        while start > 0 {
+            match pattern[start - 1] {
+                Token::Numeric(Mode::Integer, _, _) => {}
+                Token::GroupingSep(_, _) => sig_grp = true,
+                _ => break,
+            }
+            start -= 1;
+        }
+
+        let mut end = first;
+        let mut min_sig = 0;
+        while matches!(pattern.get(end), Some(Token::Significant(_))) {
+            min_sig += 1;
+            end += 1;
+        }
+        let mut max_sig = min_sig;
+        while matches!(pattern.get(end), Some(Token::Numeric(Mode::Integer, _, _))) {
+            max_sig += 1;
+            end += 1;
+        }
+
+        pattern.splice(start..end, [Token::Significant(false)]);
+        if pattern
+            .iter()
+            .filter(|t| matches!(t, Token::Significant(_)))
+            .count()
+            > 1
+        {
+            return Err(NumberFmtError::ParseInvalidSignificant);
+        }
+
+        Ok((min_sig, max_sig, sig_grp))
+    }
+
+    /// Rounding increment from the digits "1" to "8" in the pattern.
+    fn pattern_increment(pattern: &[Token]) -> Result<Option<(u32, u32)>, NumberFmtError> {
+        let mut increment = 0u32;
+        let mut scale = 0u32;
+        for t in pattern {
+            let (mode, digit) = match t {
+                Token::Digit0(mode, _, digit) | Token::Digit(mode, _, digit) => {
+                    (*mode, *digit as u32)
+                }
+                Token::Numeric(mode, _, _) => (*mode, 0),
+                _ => continue,
+            };
+            match mode {
+                Mode::Integer => {}
+                Mode::Fraction => scale += 1,
+                Mode::Exponent => continue,
+            }
+            increment = increment
+                .checked_mul(10)
+                .and_then(|v| v.checked_add(digit))
+                .ok_or(NumberFmtError::ParseInvalidDigit)?;
+        }
+        if increment == 0 {
+            return Ok(None);
+        }
+        while scale > 0 && increment % 10 == 0 {
+            increment /= 10;
+            scale -= 1;
+        }
+        Ok(Some((increment, scale)))
+    }
+
     /// Parses the format string. Uses the default symbol table.
-    fn parse_tokens(pattern: &str) -> Result<Vec<Token>, NumberFmtError> {
+    ///
+    /// Returns the tokens for each section of the pattern.
+    fn parse_tokens(pattern: &str) -> Result<Vec<Vec<Token>>, NumberFmtError> {
         let mut esc = false;
         let mut mode = Mode::Integer;
+        let mut has_int_0 = false;
 
+        let mut sections = Vec::new();
         let mut tok = Vec::new();
 
         for m in pattern.chars() {
@@ -518,8 +3229,24 @@ impl NumberFormat {
                 Token::Separator(m)
             } else {
                 match m {
-                    '0' => Token::Digit0(mode, 0),
-                    '9' => Token::Digit(mode, 0),
+                    '0' => {
+                        if mode == Mode::Integer {
+                            has_int_0 = true;
+                        }
+                        Token::Digit0(mode, 0, 0)
+                    }
+                    '1'..='8' => {
+                        if mode == Mode::Exponent {
+                            return Err(NumberFmtError::ParseInvalidExp);
+                        }
+                        // no padding with 0 unless there is a "0" to the left.
+                        if mode == Mode::Integer && !has_int_0 {
+                            Token::Digit(mode, 0, m as u8 - b'0')
+                        } else {
+                            Token::Digit0(mode, 0, m as u8 - b'0')
+                        }
+                    }
+                    '9' => Token::Digit(mode, 0, 0),
                     '#' => Token::Numeric(mode, 0, false),
                     '.' => {
                         if matches!(mode, Mode::Fraction | Mode::Exponent) {
@@ -535,7 +3262,16 @@ impl NumberFormat {
                         mode = Mode::Fraction;
                         Token::DecimalSepAlways
                     }
-                    ',' => Token::GroupingSep(0, false),
+                    ',' => {
+                        if mode == Mode::Fraction {
+                            Token::FractionGrp(0)
+                        } else {
+                            Token::GroupingSep(0, false)
+                        }
+                    }
+                    'S' => Token::SignTxt,
+                    '(' => Token::ParenOpen,
+                    ')' => Token::ParenClose,
                     '-' => {
                         if mode == Mode::Integer {
                             Token::SignInt
@@ -569,19 +3305,249 @@ impl NumberFormat {
                         Token::ExponentUpper
                     }
                     '$' => Token::Currency,
+                    '@' => {
+                        if mode != Mode::Integer {
+                            return Err(NumberFmtError::ParseInvalidSignificant);
+                        }
+                        Token::Significant(false)
+                    }
+                    '%' => Token::Percent,
+                    ';' => {
+                        Self::literal_section(&mut tok);
+                        sections.push(tok);
+                        tok = Vec::new();
+                        mode = Mode::Integer;
+                        has_int_0 = false;
+                        continue;
+                    }
                     '\\' => {
                         esc = true;
                         continue;
                     }
                     ' ' => Token::Separator(' '),
+                    '‰' => Token::PerMille,
                     c if c.is_ascii() => return Err(NumberFmtError::ParseUnescaped),
                     c => Token::Separator(c),
                 }
             };
             tok.push(mask);
         }
+        Self::literal_section(&mut tok);
+        sections.push(tok);
+
+        Ok(sections)
+    }
+
+    /// A section without digits is literal text. The sign chars
+    /// "-", "+", "S" and the parentheses are shown as they are.
+    fn literal_section(tok: &mut [Token]) {
+        let has_digits = tok.iter().any(|t| {
+            matches!(
+                t,
+                Token::Digit0(_, _, _)
+                    | Token::Digit(_, _, _)
+                    | Token::Numeric(_, _, _)
+                    | Token::Significant(_)
+            )
+        });
+        if has_digits {
+            return;
+        }
+        for t in tok.iter_mut() {
+            *t = match *t {
+                Token::SignInt | Token::SignExp => Token::Separator('-'),
+                Token::PlusInt | Token::PlusExp => Token::Separator('+'),
+                Token::SignTxt => Token::Separator('S'),
+                Token::ParenOpen => Token::Separator('('),
+                Token::ParenClose => Token::Separator(')'),
+                Token::ExponentLower => Token::Separator('e'),
+                Token::ExponentUpper => Token::Separator('E'),
+                t => t,
+            };
+        }
+    }
+
+    /// Sets the rounding mode.
+    ///
+    /// Without an explicit rounding mode the std-format does the rounding.
+    /// For floats this is half-even on the exact binary value.
+    pub fn rounding(mut self, rounding: RoundingMode) -> Self {
+        self.sections_mut(|v| v.rounding = Some(rounding));
+        self
+    }
+
+    /// Sets the rounding increment. The number is rounded to a multiple
+    /// of increment * 10^-scale, `increment(5, 2)` rounds to 0.05.
+    ///
+    /// The same can be done in the pattern with the digits "1" to "8".
+    /// "#,##0.05" rounds to 0.05, "#,#50" rounds to 50. In the integer
+    /// they are padded with 0 only if there is a "0" to the left, like
+    /// "9" otherwise.
+    ///
+    /// Uses the rounding mode, which defaults to half-even.
+    /// With an exponent the mantissa is rounded to the increment,
+    /// "0.05e0" shows 1.234 as 1.25e0.
+    pub fn increment(mut self, increment: u32, scale: u32) -> Self {
+        let increment = if increment != 0 {
+            Some((increment, scale))
+        } else {
+            None
+        };
+        self.sections_mut(|v| v.increment = increment);
+        self
+    }
+
+    /// General notation like printf's `%g`.
+    ///
+    /// Numbers with an exponent in the range min_exp..max_exp use the
+    /// fixed layout, all others the exponent layout of the pattern.
+    /// The exponent is blanked in the fixed layout, so the width stays
+    /// the same. The exponent is taken after rounding, 9.996 becomes
+    /// 1.00e1 and is shown as 10.00.
+    ///
+    /// The pattern must have an exponent, and enough integer digits for
+    /// max_exp. Works best with significant digits, "@@##e#0" with
+    /// `general(-4, 6)` shows 0.000123 and 123500 and 1.2e10.
+    ///
+    /// Fails with [NumberFmtError::ParseInvalidExp] if the pattern has
+    /// no exponent.
+    pub fn general(mut self, min_exp: i32, max_exp: i32) -> Result<Self, NumberFmtError> {
+        if !self.has_exp {
+            return Err(NumberFmtError::ParseInvalidExp);
+        }
+        self.sections_mut(|v| v.general = Some((min_exp, max_exp)));
+        Ok(self)
+    }
+
+    /// Engineering notation.
+    ///
+    /// The exponent is always a multiple of 3 and the integer part
+    /// has 1 to 3 digits. The pattern needs 3 integer digits.
+    ///
+    /// The mantissa keeps the fraction digits of the pattern whatever
+    /// the exponent is, so 999.96 rounds up to 1.0E3. A pattern without
+    /// exponent ignores this.
+    ///
+    /// ```
+    /// use format_num_pattern::NumberFormat;
+    ///
+    /// let fmt = NumberFormat::new("##0.0E#0").expect("works").engineering();
+    /// assert_eq!(fmt.fmt(12345).expect("works"), " 12.3E3 ");
+    /// assert_eq!(fmt.fmt(0.000456).expect("works"), "456.0E-6");
+    /// assert_eq!(fmt.fmt(999.96).expect("works"), "  1.0E3 ");
+    /// ```
+    pub fn engineering(mut self) -> Self {
+        self.sections_mut(|v| v.engineering = true);
+        self
+    }
+
+    /// The number is an integer count of minor units like cents.
+    ///
+    /// `minor_units(2)` formats 12345 as 123.45 and parses 123.45 back
+    /// to 12345. The decimal point is moved in the digits, so this is
+    /// exact for all integer types. A fraction with more digits can not
+    /// be parsed into an integer.
+    pub fn minor_units(mut self, digits: u32) -> Self {
+        self.sections_mut(|v| {
+            v.scale += v.minor_units as i32 - digits as i32;
+            v.minor_units = digits;
+        });
+        self
+    }
+
+    /// Sets the handling of numbers with more integer digits than
+    /// the pattern allows. The default is [Overflow::Error].
+    pub fn overflow(mut self, overflow: Overflow) -> Self {
+        self.sections_mut(|v| v.overflow = overflow);
+        self
+    }
+
+    /// Expands the pattern by n integer digits.
+    ///
+    /// The new digits repeat the leftmost digit and the size of the
+    /// leftmost group. With sign one more "#" is added as place for
+    /// the sign.
+    fn expand(&self, n: u32, sign: bool) -> Result<NumberFormat, NumberFmtError> {
+        let is_int = |t: &Token| {
+            matches!(
+                t,
+                Token::Digit0(Mode::Integer, _, _)
+                    | Token::Digit(Mode::Integer, _, _)
+                    | Token::Numeric(Mode::Integer, _, _)
+                    | Token::GroupingSep(_, _)
+            )
+        };
+        let mut seps = self.tok.iter().filter_map(|t| match t {
+            Token::GroupingSep(x, _) => Some(*x),
+            _ => None,
+        });
+        let grp = match (seps.next(), seps.next()) {
+            (Some(x1), Some(x2)) => Some((x1, x1 - x2)),
+            (Some(x1), None) => Some((x1, x1)),
+            _ => None,
+        };
+
+        let (idx, digit) = if let Some(idx) = self.tok.iter().position(is_int) {
+            let digit = match self.tok[idx..]
+                .iter()
+                .find(|t| !matches!(t, Token::GroupingSep(_, _)))
+            {
+                Some(Token::Digit0(_, _, _)) => Token::Digit0(Mode::Integer, 0, 0),
+                Some(Token::Digit(_, _, _)) => Token::Digit(Mode::Integer, 0, 0),
+                _ => Token::Numeric(Mode::Integer, 0, false),
+            };
+            (idx, digit)
+        } else if let Some(idx) = self
+            .tok
+            .iter()
+            .position(|t| matches!(t, Token::DecimalSep | Token::DecimalSepAlways))
+        {
+            (idx, Token::Numeric(Mode::Integer, 0, false))
+        } else {
+            return Err(NumberFmtError::FmtLenInt);
+        };
+
+        let mut expand = Vec::new();
+        if sign {
+            expand.push(Token::Numeric(Mode::Integer, 0, false));
+        }
+        for i in (self.len_int..self.len_int + n).rev() {
+            expand.push(digit);
+            if let Some((x1, size)) = grp.filter(|_| !self.sym_grp) {
+                if size > 0 && i > x1 && (i - x1) % size == 0 {
+                    expand.push(Token::GroupingSep(0, false));
+                }
+            }
+        }
 
-        Ok(tok)
+        let mut tok = self.tok.clone();
+        tok.splice(idx..idx, expand);
+        if self.sym_grp {
+            Self::relayout_grouping(&mut tok, &self.sym);
+        }
+        // the digit indizes are reassigned, the increment is kept.
+        let layout = Self::news_tok(tok, self.sym)?;
+
+        Ok(NumberFormat {
+            min_int_sign: layout.min_int_sign,
+            len_int: layout.len_int,
+            tok: layout.tok,
+            neg: None,
+            zero: None,
+            nan: None,
+            ..self.clone()
+        })
+    }
+
+    /// Apply to all sections.
+    fn sections_mut(&mut self, mut f: impl FnMut(&mut NumberFormat)) {
+        f(self);
+        for section in [&mut self.neg, &mut self.zero, &mut self.nan]
+            .into_iter()
+            .flatten()
+        {
+            f(section);
+        }
     }
 
     /// Symbols
@@ -632,6 +3598,24 @@ impl NumberFormat {
     pub fn parse<F: FromStr>(&self, s: &str) -> Result<F, NumberFmtError> {
         core::parse_fmt(s, self, &self.sym)
     }
+
+    /// Parse into the parts of the number using the exact format.
+    /// The parts borrow from buf. See [core::unmap_parts()]
+    #[inline]
+    pub fn parse_parts<'a>(
+        &self,
+        s: &str,
+        buf: &'a mut String,
+    ) -> Result<NumberParts<'a>, NumberFmtError> {
+        core::unmap_parts(s, self, &self.sym, buf)
+    }
+
+    /// Parse an integer using the exact format.
+    /// See [core::parse_fmt_int()]
+    #[inline]
+    pub fn parse_int<F: FromStr>(&self, s: &str) -> Result<F, NumberFmtError> {
+        core::parse_fmt_int(s, self, &self.sym)
+    }
 }
 
 /// Parses a number from a &str.
@@ -655,6 +3639,83 @@ impl ParseNumber for &str {
     }
 }
 
+/// Parts of a parsed number.
+///
+/// The digits are ascii and borrowed from the raw number, they are not
+/// normalized. The fraction keeps its trailing zeros, so the precision
+/// of the input can be checked.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct NumberParts<'a> {
+    /// Negative sign.
+    pub negative: bool,
+    /// Integer digits.
+    pub int: &'a str,
+    /// Fraction digits.
+    pub frac: &'a str,
+    /// Negative exponent sign.
+    pub exp_negative: bool,
+    /// Exponent digits. Empty if there is no exponent.
+    pub exp: &'a str,
+    /// NaN. All digits are empty.
+    pub nan: bool,
+    /// Infinity. All digits are empty.
+    pub infinite: bool,
+}
+
+impl<'a> NumberParts<'a> {
+    /// Splits a raw number as produced by [core::unmap_num()] or
+    /// [core::clean_num()]. Decimal point is '.', exponent is 'e'.
+    pub fn from_raw(raw: &'a str) -> Result<Self, NumberFmtError> {
+        let (negative, num) = match raw.strip_prefix('-') {
+            Some(num) => (true, num),
+            None => (false, raw.strip_prefix('+').unwrap_or(raw)),
+        };
+        match num {
+            "NaN" => {
+                return Ok(NumberParts {
+                    nan: true,
+                    ..Default::default()
+                })
+            }
+            "inf" => {
+                return Ok(NumberParts {
+                    negative,
+                    infinite: true,
+                    ..Default::default()
+                })
+            }
+            _ => {}
+        }
+
+        let (num, exp) = num.split_once('e').unwrap_or((num, ""));
+        let (exp_negative, exp) = match exp.strip_prefix('-') {
+            Some(exp) => (true, exp),
+            None => (false, exp.strip_prefix('+').unwrap_or(exp)),
+        };
+        let (int, frac) = num.split_once('.').unwrap_or((num, ""));
+
+        if !int.bytes().all(|c| c.is_ascii_digit()) {
+            return Err(NumberFmtError::ParseInvalidDigit);
+        }
+        if !frac.bytes().all(|c| c.is_ascii_digit()) {
+            return Err(NumberFmtError::ParseInvalidDigit);
+        }
+        if !exp.bytes().all(|c| c.is_ascii_digit()) {
+            return Err(NumberFmtError::ParseInvalidExp);
+        }
+
+        Ok(NumberParts {
+            negative,
+            int,
+            frac,
+            exp_negative,
+            exp,
+            nan: false,
+            infinite: false,
+        })
+    }
+}
+
 /// Format a number according to a format string.
 pub trait DisplayNumber
 where
@@ -678,13 +3739,13 @@ where
 pub struct FormattedNumber<'a, Number> {
     num: Number,
     format: NumberFormat,
-    sym: &'a NumberSymbols,
+    sym: PhantomData<&'a NumberSymbols>,
 }
 
 impl<'a, Number: Copy + LowerExp + Display> Display for FormattedNumber<'a, Number> {
     #[inline]
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        match core::format_to(self.num, &self.format, self.sym, f) {
+        match core::format_to(self.num, &self.format, &self.format.sym, f) {
             Ok(_) => Ok(()),
             Err(_) => Err(fmt::Error),
         }
@@ -697,13 +3758,13 @@ impl<'a, Number: Copy + LowerExp + Display> Display for FormattedNumber<'a, Numb
 #[derive(Debug)]
 pub struct RefFormattedNumber<'a, Number> {
     num: Number,
-    format: &'a NumberFormat,
+    format: Cow<'a, NumberFormat>,
 }
 
 impl<'a, Number: Copy + LowerExp + Display> Display for RefFormattedNumber<'a, Number> {
     #[inline]
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        match core::format_to(self.num, self.format, &self.format.sym, f) {
+        match core::format_to(self.num, &self.format, &self.format.sym, f) {
             Ok(_) => Ok(()),
             Err(_) => Err(fmt::Error),
         }
@@ -721,14 +3782,17 @@ macro_rules! define_fmt {
             ) -> Result<FormattedNumber<'a, Self>, NumberFmtError> {
                 Ok(FormattedNumber {
                     num: *self,
-                    format: NumberFormat::new(pattern)?,
-                    sym,
+                    format: NumberFormat::news(pattern, *sym)?,
+                    sym: PhantomData,
                 })
             }
 
             #[inline]
             fn fmt<'a>(&self, format: &'a NumberFormat) -> RefFormattedNumber<'a, Self> {
-                RefFormattedNumber { num: *self, format }
+                RefFormattedNumber {
+                    num: *self,
+                    format: Cow::Borrowed(format),
+                }
             }
         }
     };
@@ -751,15 +3815,181 @@ define_fmt!(isize);
 #[cfg(feature = "rust_decimal")]
 define_fmt!(Decimal);
 
+/// An amount of money in a currency.
+///
+/// Display and LowerExp show the plain amount, they are the raw input
+/// for formatting. With [DisplayNumber] the currency symbol and the
+/// minor units of the currency are used instead of the ones of the
+/// format. Everything else stays the same, so any format that shows
+/// a currency will do.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Money<Number> {
+    /// Amount
+    pub amount: Number,
+    /// Currency
+    pub currency: &'static Currency,
+}
+
+impl<Number> Money<Number> {
+    /// New amount of money.
+    pub fn new(amount: Number, currency: &'static Currency) -> Self {
+        Self { amount, currency }
+    }
+}
+
+impl<Number: Copy + LowerExp + Display> Money<Number> {
+    /// The given format for this currency.
+    ///
+    /// Replaces the currency symbol and the number of fraction digits
+    /// with the ones of the currency. Pattern, sections, rounding,
+    /// increment and overflow stay the same. Uses the ISO code if the
+    /// format shows an ISO code, the symbol otherwise.
+    ///
+    /// Fails with [NumberFmtError::FmtNoCurrency] if the format shows
+    /// no currency.
+    pub fn number_format(&self, format: &NumberFormat) -> Result<NumberFormat, NumberFmtError> {
+        let txt = match iso_code_sep(format.sym.currency_sym.as_str()) {
+            Some(sep) => format!("{}{}", self.currency.code, sep),
+            None => self.currency.symbol.to_string(),
+        };
+        format.with_currency(
+            CurrencySym::new(txt.as_str()),
+            self.currency.minor_units as u32,
+        )
+    }
+
+    /// Format using the locale of the [NumberFormat], see
+    /// [Money::number_format].
+    ///
+    /// Fails with [NumberFmtError::FmtNoCurrency] if the format shows
+    /// no currency.
+    pub fn try_fmt<'a>(
+        &self,
+        format: &'a NumberFormat,
+    ) -> Result<RefFormattedNumber<'a, Self>, NumberFmtError> {
+        Ok(RefFormattedNumber {
+            num: *self,
+            format: Cow::Owned(self.number_format(format)?),
+        })
+    }
+}
+
+impl<Number: FromStr> Money<Number> {
+    /// Parses amount and currency.
+    ///
+    /// Accepts every display variant of a currency with the locale of
+    /// the given format. Longer symbols are tried first, and the code,
+    /// name and symbol before the narrow symbol. The narrow symbols are
+    /// ambiguous, "kr" resolves to the first currency in [Currency::all]
+    /// that parses.
+    ///
+    /// Integer amounts accept an all-zero fraction, see [parse_fmt_int].
+    pub fn parse_fmt(s: &str, format: &NumberFormat) -> Result<Self, NumberFmtError> {
+        let mut found = Vec::new();
+        for display in [
+            CurrencyDisplay::Code,
+            CurrencyDisplay::Name,
+            CurrencyDisplay::Symbol,
+            CurrencyDisplay::NarrowSymbol,
+        ] {
+            for currency in Currency::all() {
+                let txt = currency.display(display);
+                if s.contains(txt) {
+                    found.push((currency, txt));
+                }
+            }
+        }
+        found.sort_by_key(|(_, txt)| std::cmp::Reverse(txt.len()));
+
+        // an ISO code keeps the separator of the format.
+        let sep = iso_code_sep(format.sym.currency_sym.as_str()).unwrap_or("");
+
+        let mut err = NumberFmtError::ParseInvalidCurrency;
+        for (currency, txt) in found {
+            let txt = if txt == currency.code {
+                format!("{}{}", txt, sep)
+            } else {
+                txt.to_string()
+            };
+            let sym = CurrencySym::new(txt.as_str());
+            let format = format.with_currency(sym, currency.minor_units as u32)?;
+            // integer amounts accept a zero fraction.
+            match core::parse_fmt(s, &format, &format.sym)
+                .or_else(|_| core::parse_fmt_int(s, &format, &format.sym))
+            {
+                Ok(amount) => return Ok(Money { amount, currency }),
+                Err(e) => err = e,
+            }
+        }
+        Err(err)
+    }
+}
+
+impl<Number: Display> Display for Money<Number> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.amount, f)
+    }
+}
+
+impl<Number: LowerExp> LowerExp for Money<Number> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        LowerExp::fmt(&self.amount, f)
+    }
+}
+
+impl<Number: Copy + LowerExp + Display> DisplayNumber for Money<Number> {
+    /// Format using the format-string. Same as `fmt()` with the format
+    /// of the pattern, see [Money::number_format].
+    fn format<'a>(
+        &self,
+        pattern: &'a str,
+        sym: &'a NumberSymbols,
+    ) -> Result<FormattedNumber<'a, Self>, NumberFmtError> {
+        let format = NumberFormat::news(pattern, *sym)?;
+        Ok(FormattedNumber {
+            num: *self,
+            format: self.number_format(&format)?,
+            sym: PhantomData,
+        })
+    }
+
+    /// Format using the locale of the [NumberFormat]. See [Money::number_format].
+    /// A format without currency is used as is, [Money::try_fmt] reports it.
+    fn fmt<'a>(&self, format: &'a NumberFormat) -> RefFormattedNumber<'a, Self> {
+        RefFormattedNumber {
+            num: *self,
+            format: match self.number_format(format) {
+                Ok(format) => Cow::Owned(format),
+                Err(_) => Cow::Borrowed(format),
+            },
+        }
+    }
+}
+
+// separator after an ISO code. INT_CURR_SYMBOL has three ascii upper
+// case letters and a separator.
+fn iso_code_sep(s: &str) -> Option<&str> {
+    let code = s.get(..3)?;
+    let sep = &s[3..];
+    if code.bytes().all(|v| v.is_ascii_uppercase()) && sep.trim().is_empty() {
+        Some(sep)
+    } else {
+        None
+    }
+}
+
 pub mod core {
-    use crate::{Mode, NumberFmtError, NumberFormat, NumberSymbols, Token};
+    use crate::{
+        Mode, NumberFmtError, NumberFormat, NumberParts, NumberSymbols, Overflow, RoundingMode,
+        ShortSym, Token,
+    };
     #[allow(unused_imports)]
     use log::debug;
     use memchr::memchr;
     use std::cell::Cell;
     use std::cmp::max;
     use std::fmt::{Display, LowerExp, Write as FmtWrite};
-    use std::str::FromStr;
+    use std::str::{Chars, FromStr};
 
     fn split_num(value: &str) -> (&str, &str, &str, &str, &str) {
         // everything is ascii
@@ -821,49 +4051,220 @@ pub mod core {
         sym: &NumberSymbols,
         out: &mut W,
     ) -> Result<(), NumberFmtError> {
-        for c in formatted.chars() {
-            if c.is_ascii_digit() {
-                out.write_char(c)?;
-            } else if c == sym.negative_sym || c == '-' {
+        let mut rest = formatted;
+        while let Some(c) = rest.chars().next() {
+            if let Some(r) = strip_sym(rest, &sym.negative_sym) {
                 out.write_char('-')?;
-            } else if c == sym.decimal_sep {
+                rest = r;
+                continue;
+            } else if let Some(r) = strip_sym(rest, &sym.decimal_sep) {
                 out.write_char('.')?;
+                rest = r;
+                continue;
+            }
+
+            let d = sym.ascii_digit(c);
+            if d.is_ascii_digit() {
+                out.write_char(d)?;
+            } else if c == '-' {
+                out.write_char('-')?;
             } else if c == sym.exponent_lower_sym || c == sym.exponent_upper_sym {
                 out.write_char('e')?;
             }
+            rest = &rest[c.len_utf8()..];
         }
         Ok(())
     }
 
+    /// Strips a symbol from the start of the input.
+    #[inline]
+    fn strip_sym<'a>(s: &'a str, sym: &ShortSym) -> Option<&'a str> {
+        let sym = sym.as_str();
+        if sym.is_empty() {
+            None
+        } else {
+            s.strip_prefix(sym)
+        }
+    }
+
+    /// Matches a symbol at the position of the current char c.
+    /// On success the iterator is moved behind the symbol.
+    #[inline]
+    fn eat_sym<'a>(formatted: &'a str, c: char, jt: &mut Chars<'a>, sym: &ShortSym) -> bool {
+        let rest = &formatted[formatted.len() - jt.as_str().len() - c.len_utf8()..];
+        match strip_sym(rest, sym) {
+            Some(rest) => {
+                *jt = rest.chars();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Sections of a NumberFormat.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Section {
+        Positive,
+        Negative,
+        Zero,
+        NaN,
+    }
+
     /// Unmap the formatted string back to a format that `f64::parse()` can understand.
-    #[allow(clippy::if_same_then_else)]
+    ///
+    /// If the format has more than one section, each section is tried in turn.
+    /// If none matches, the error for the first section is returned.
     pub fn unmap_num<W: FmtWrite>(
         formatted: &str,
         format: &NumberFormat,
         sym: &NumberSymbols,
         out: &mut W,
+    ) -> Result<(), NumberFmtError> {
+        if sym.zero_digit != '0' {
+            let ascii = formatted
+                .chars()
+                .map(|c| sym.ascii_digit(c))
+                .collect::<String>();
+            let sym = NumberSymbols {
+                zero_digit: '0',
+                ..*sym
+            };
+            return unmap_num(ascii.as_str(), format, &sym, out);
+        }
+
+        // NaN and infinity are right-aligned.
+        let special = formatted.trim_start();
+        if !sym.nan_sym.as_str().is_empty() && special == sym.nan_sym.as_str() {
+            out.write_str("NaN")?;
+            return Ok(());
+        }
+        if !sym.infinity_sym.as_str().is_empty() {
+            if special == sym.infinity_sym.as_str() {
+                out.write_str("inf")?;
+                return Ok(());
+            }
+            if let Some(inf) = strip_sym(special, &sym.negative_sym) {
+                if inf == sym.infinity_sym.as_str() {
+                    out.write_str("-inf")?;
+                    return Ok(());
+                }
+            }
+        }
+
+        // with several sections the input must match the section completely.
+        let strict = format.neg.is_some() || format.zero.is_some() || format.nan.is_some();
+
+        let err = match unmap_expand(formatted, format, sym, Section::Positive, strict, out) {
+            Ok(()) => return Ok(()),
+            Err(e) => e,
+        };
+        for (section, kind) in [
+            (&format.neg, Section::Negative),
+            (&format.zero, Section::Zero),
+            (&format.nan, Section::NaN),
+        ] {
+            if let Some(section) = section {
+                if unmap_expand(formatted, section, sym, kind, strict, out).is_ok() {
+                    return Ok(());
+                }
+            }
+        }
+        Err(err)
+    }
+
+    /// Unmap one section of the format. With [Overflow::Expand] the
+    /// section is expanded until it fits the input.
+    fn unmap_expand<W: FmtWrite>(
+        formatted: &str,
+        format: &NumberFormat,
+        sym: &NumberSymbols,
+        section: Section,
+        strict: bool,
+        out: &mut W,
+    ) -> Result<(), NumberFmtError> {
+        let err = match unmap_section(formatted, format, sym, section, strict, out) {
+            Ok(()) => return Ok(()),
+            Err(e) => e,
+        };
+        if format.overflow == Overflow::Expand {
+            let digits = formatted.chars().filter(char::is_ascii_digit).count() as u32;
+            for n in 0..=digits {
+                for sign in [false, true] {
+                    if n == 0 && !sign {
+                        continue;
+                    }
+                    let Ok(expanded) = format.expand(n, sign) else {
+                        return Err(err);
+                    };
+                    if unmap_section(formatted, &expanded, sym, section, strict, out).is_ok() {
+                        return Ok(());
+                    }
+                }
+            }
+        }
+        Err(err)
+    }
+
+    /// Number of digits right of the first grouping separator.
+    fn first_grp(format: &NumberFormat, sym: &NumberSymbols) -> Option<u32> {
+        if format.max_sig > 0 {
+            Some(sym.grouping.map(|v| v.0 as u32).unwrap_or(3))
+        } else {
+            format
+                .tok
+                .iter()
+                .filter_map(|t| match t {
+                    Token::GroupingSep(x, _) => Some(*x),
+                    _ => None,
+                })
+                .min()
+        }
+    }
+
+    /// Unmap one section of the format.
+    ///
+    /// If strict the whole input must match the section, otherwise
+    /// missing digits at the end and trailing text are ignored.
+    #[allow(clippy::if_same_then_else)]
+    fn unmap_section<W: FmtWrite>(
+        formatted: &str,
+        format: &NumberFormat,
+        sym: &NumberSymbols,
+        section: Section,
+        strict: bool,
+        out: &mut W,
     ) -> Result<(), NumberFmtError> {
         let mut buf_sign = String::new();
         let mut buf_int = String::new();
         let mut buf_frac = String::new();
         let mut buf_exp_sign = String::new();
         let mut buf_exp = String::new();
+        // general notation with the fixed layout.
+        let mut blank_exp = false;
+        // grouping separators and digits in their place.
+        let mut grp_sep = false;
+        let mut grp_digit = false;
 
-        let mut it = format.tok.iter();
         let mut jt = formatted.chars();
-        loop {
-            let Some(t) = it.next() else {
-                break;
-            };
+        let mut it = format.tok.iter();
+        for t in it.by_ref() {
+            if matches!(t, Token::GroupingSep(_, _)) && sym.decimal_grp.is_none() {
+                // not displayed
+                continue;
+            }
             let Some(c) = jt.next() else {
+                // trailing blanks may be missing.
+                if strict && !can_be_blank(t) {
+                    return Err(NumberFmtError::ParseInvalidDigit);
+                }
                 break;
             };
 
             match t {
                 Token::SignInt => {
-                    if c == sym.negative_sym {
+                    if eat_sym(formatted, c, &mut jt, &sym.negative_sym) {
                         buf_sign.push('-');
-                    } else if c == sym.positive_sym {
+                    } else if eat_sym(formatted, c, &mut jt, &sym.positive_sym) {
                         // ok
                     } else {
                         return Err(NumberFmtError::ParseInvalidSign);
@@ -878,14 +4279,102 @@ pub mod core {
                         return Err(NumberFmtError::ParseInvalidSign);
                     }
                 }
-                Token::Digit0(Mode::Integer, _) => {
+                Token::SignTxt => {
+                    // the rest of the input including c.
+                    let rest = &formatted[formatted.len() - jt.as_str().len() - c.len_utf8()..];
+
+                    let positive = sym.positive_txt.as_str();
+                    let negative = sym.negative_txt.as_str();
+                    let txt = if !negative.is_empty() && rest.starts_with(negative) {
+                        buf_sign.push('-');
+                        negative
+                    } else if rest.starts_with(positive) {
+                        positive
+                    } else {
+                        return Err(NumberFmtError::ParseInvalidSign);
+                    };
+
+                    // padding
+                    jt = rest[txt.len()..].chars();
+                    for _ in txt.chars().count()..sign_txt_width(sym) {
+                        match jt.clone().next() {
+                            Some(' ') => {
+                                jt.next();
+                            }
+                            None => break,
+                            Some(_) => return Err(NumberFmtError::ParseInvalidSign),
+                        }
+                    }
+                }
+                Token::Significant(can_be_sign) => {
+                    // the rest of the input including c.
+                    let rest = &formatted[formatted.len() - jt.as_str().len() - c.len_utf8()..];
+
+                    let mut kt = rest.chars();
+                    if *can_be_sign {
+                        if let Some(r) = strip_sym(kt.as_str(), &sym.negative_sym)
+                            .or_else(|| kt.as_str().strip_prefix('-'))
+                        {
+                            buf_sign.push('-');
+                            kt = r.chars();
+                        } else if let Some(r) = strip_sym(kt.as_str(), &sym.positive_sym) {
+                            kt = r.chars();
+                        }
+                    }
+                    let mut is_frac = false;
+                    let mut has_digits = false;
+                    while let Some(c) = kt.clone().next() {
+                        if !is_frac {
+                            if let Some(r) = strip_sym(kt.as_str(), &sym.decimal_sep) {
+                                buf_frac.push('.');
+                                is_frac = true;
+                                kt = r.chars();
+                                continue;
+                            }
+                        }
+                        if c.is_ascii_digit() {
+                            if is_frac {
+                                buf_frac.push(c);
+                            } else {
+                                buf_int.push(c);
+                            }
+                            has_digits = true;
+                        } else if !is_frac && Some(c) == sym.decimal_grp {
+                            // ok
+                        } else {
+                            break;
+                        }
+                        kt.next();
+                    }
+                    if !has_digits {
+                        return Err(NumberFmtError::ParseInvalidDigit);
+                    }
+                    jt = kt;
+                }
+                Token::ParenOpen => {
+                    if c == '(' {
+                        buf_sign.push('-');
+                    } else if c == ' ' {
+                        // ok
+                    } else {
+                        return Err(NumberFmtError::ParseInvalidSign);
+                    }
+                }
+                Token::ParenClose => {
+                    if (c == ')' && !buf_sign.is_empty()) || (c == ' ' && buf_sign.is_empty()) {
+                        // ok
+                    } else {
+                        return Err(NumberFmtError::ParseInvalidSign);
+                    }
+                }
+                Token::Digit0(Mode::Integer, _, _) => {
                     if c.is_ascii_digit() {
                         buf_int.push(c);
                     } else {
                         return Err(NumberFmtError::ParseInvalidDigit);
                     }
                 }
-                Token::Digit(Mode::Integer, _) => {
+                Token::Digit(Mode::Integer, _, _) => {
                     if c.is_ascii_digit() {
                         buf_int.push(c);
                     } else if c == ' ' {
@@ -897,9 +4386,9 @@ pub mod core {
                 Token::Numeric(Mode::Integer, _, _) => {
                     if c.is_ascii_digit() {
                         buf_int.push(c);
-                    } else if c == sym.negative_sym {
+                    } else if eat_sym(formatted, c, &mut jt, &sym.negative_sym) {
                         buf_sign.push('-');
-                    } else if c == sym.positive_sym || c == ' ' {
+                    } else if eat_sym(formatted, c, &mut jt, &sym.positive_sym) || c == ' ' {
                         // ok
                     } else {
                         return Err(NumberFmtError::ParseInvalidDigit);
@@ -908,10 +4397,15 @@ pub mod core {
                 Token::GroupingSep(_, _) => {
                     if let Some(decimal_grp) = sym.decimal_grp {
                         if c == decimal_grp {
-                            // ok
-                        } else if c == sym.negative_sym {
+                            // the separator may be a blank too.
+                            grp_sep |= !buf_int.is_empty();
+                        } else if sym.min_grouping > 1 && c.is_ascii_digit() {
+                            // ungrouped
+                            buf_int.push(c);
+                            grp_digit = true;
+                        } else if eat_sym(formatted, c, &mut jt, &sym.negative_sym) {
                             buf_sign.push('-');
-                        } else if c == sym.positive_sym || c == ' ' {
+                        } else if eat_sym(formatted, c, &mut jt, &sym.positive_sym) || c == ' ' {
                             // ok
                         } else {
                             return Err(NumberFmtError::ParseInvalidGroupingSep);
@@ -919,7 +4413,7 @@ pub mod core {
                     }
                 }
                 Token::DecimalSep => {
-                    if c == sym.decimal_sep {
+                    if eat_sym(formatted, c, &mut jt, &sym.decimal_sep) {
                         buf_frac.push('.');
                     } else if c == ' ' {
                         // ok
@@ -928,20 +4422,33 @@ pub mod core {
                     }
                 }
                 Token::DecimalSepAlways => {
-                    if c == sym.decimal_sep {
+                    if eat_sym(formatted, c, &mut jt, &sym.decimal_sep) {
                         buf_frac.push('.');
                     } else {
                         return Err(NumberFmtError::ParseInvalidDecimalSep);
                     }
                 }
-                Token::Digit0(Mode::Fraction, _) => {
+                Token::FractionGrp(_) => {
+                    if let Some(fraction_grp) = sym.fraction_grp {
+                        if c == fraction_grp || c == ' ' {
+                            // ok
+                        } else {
+                            return Err(NumberFmtError::ParseInvalidGroupingSep);
+                        }
+                    } else {
+                        // not displayed, c belongs to the next token.
+                        let rest = &formatted[formatted.len() - jt.as_str().len() - c.len_utf8()..];
+                        jt = rest.chars();
+                    }
+                }
+                Token::Digit0(Mode::Fraction, _, _) => {
                     if c.is_ascii_digit() {
                         buf_frac.push(c);
                     } else {
                         return Err(NumberFmtError::ParseInvalidDigit);
                     }
                 }
-                Token::Digit(Mode::Fraction, _) => {
+                Token::Digit(Mode::Fraction, _, _) => {
                     if c.is_ascii_digit() {
                         buf_frac.push(c);
                     } else if c == ' ' {
@@ -963,7 +4470,7 @@ pub mod core {
                     if c == sym.exponent_upper_sym {
                         // ok
                     } else if c == ' ' {
-                        // ok
+                        blank_exp = format.general.is_some();
                     } else {
                         return Err(NumberFmtError::ParseInvalidExp);
                     }
@@ -972,19 +4479,21 @@ pub mod core {
                     if c == sym.exponent_lower_sym {
                         // ok
                     } else if c == ' ' {
-                        // ok
+                        blank_exp = format.general.is_some();
                     } else {
                         return Err(NumberFmtError::ParseInvalidExp);
                     }
                 }
-                Token::Digit0(Mode::Exponent, _) => {
+                Token::Digit0(Mode::Exponent, _, _) => {
                     if c.is_ascii_digit() {
                         buf_exp.push(c);
+                    } else if c == ' ' && blank_exp {
+                        // ok
                     } else {
                         return Err(NumberFmtError::ParseInvalidDigit);
                     }
                 }
-                Token::Digit(Mode::Exponent, _) => {
+                Token::Digit(Mode::Exponent, _, _) => {
                     if c.is_ascii_digit() {
                         buf_exp.push(c);
                     } else if c == ' ' {
@@ -996,18 +4505,21 @@ pub mod core {
                 Token::Numeric(Mode::Exponent, _, _) => {
                     if c.is_ascii_digit() {
                         buf_exp.push(c);
-                    } else if c == sym.negative_sym {
+                    } else if eat_sym(formatted, c, &mut jt, &sym.negative_sym) {
                         buf_exp_sign.push('-');
-                    } else if c == sym.positive_sym || c == ' ' {
+                    } else if eat_sym(formatted, c, &mut jt, &sym.positive_sym) || c == ' ' {
                         // ok
                     } else {
                         return Err(NumberFmtError::ParseInvalidDigit);
                     }
                 }
                 Token::SignExp => {
-                    if c == sym.negative_sym {
+                    if eat_sym(formatted, c, &mut jt, &sym.negative_sym) {
                         buf_exp_sign.push('-');
-                    } else if c == sym.positive_sym || c == '+' {
+                    } else if eat_sym(formatted, c, &mut jt, &sym.positive_sym)
+                        || c == '+'
+                        || (c == ' ' && blank_exp)
+                    {
                         // ok
                     } else {
                         return Err(NumberFmtError::ParseInvalidExpSign);
@@ -1016,57 +4528,508 @@ pub mod core {
                 Token::PlusExp => {
                     if c == '-' {
                         buf_exp_sign.push('-');
-                    } else if c == '+' {
+                    } else if c == '+' || (c == ' ' && blank_exp) {
                         // ok
                     } else {
                         return Err(NumberFmtError::ParseInvalidExpSign);
                     }
                 }
 
-                Token::Currency => {
-                    let mut kt = sym.currency_sym.as_str().chars();
-                    let s = kt.next();
-                    if Some(c) != s {
-                        return Err(NumberFmtError::ParseInvalidCurrency);
-                    }
+                Token::Currency => {
+                    let mut kt = sym.currency_sym.as_str().chars();
+                    let s = kt.next();
+                    if Some(c) != s {
+                        return Err(NumberFmtError::ParseInvalidCurrency);
+                    }
+
+                    loop {
+                        match kt.next() {
+                            None => {
+                                break;
+                            }
+                            Some(s) => {
+                                let Some(c) = jt.next() else {
+                                    return Err(NumberFmtError::ParseInvalidCurrency);
+                                };
+                                if c != s {
+                                    return Err(NumberFmtError::ParseInvalidCurrency);
+                                }
+                            }
+                        }
+                    }
+                }
+
+                Token::Percent => {
+                    if c == sym.percent_sym {
+                        // ok
+                    } else {
+                        return Err(NumberFmtError::ParseInvalidSeparator);
+                    }
+                }
+                Token::PerMille => {
+                    if c == sym.permille_sym {
+                        // ok
+                    } else {
+                        return Err(NumberFmtError::ParseInvalidSeparator);
+                    }
+                }
+
+                Token::Separator(sep) => {
+                    if c == *sep {
+                        // ok
+                    } else {
+                        return Err(NumberFmtError::ParseInvalidSeparator);
+                    }
+                }
+            }
+        }
+
+        // a digit in place of a grouping separator is only ok, if
+        // min_grouping leaves out all grouping.
+        if grp_digit {
+            let len_int = buf_int.trim_start_matches('0').len() as u32;
+            match first_grp(format, sym) {
+                Some(first_grp) if !grp_sep && len_int < first_grp + sym.min_grouping as u32 => {}
+                _ => return Err(NumberFmtError::ParseInvalidGroupingSep),
+            }
+        }
+
+        if strict {
+            // trailing blanks may be missing.
+            if !it.all(can_be_blank) {
+                return Err(NumberFmtError::ParseInvalidDigit);
+            }
+            // only trailing blanks are acceptable.
+            if jt.any(|c| c != ' ') {
+                return Err(NumberFmtError::ParseTrailing);
+            }
+        }
+
+        match section {
+            Section::Positive => {}
+            Section::Negative => {
+                buf_sign.clear();
+                buf_sign.push('-');
+            }
+            Section::Zero => {
+                if buf_int.is_empty() && buf_frac.is_empty() {
+                    out.write_char('0')?;
+                    return Ok(());
+                }
+            }
+            Section::NaN => {
+                out.write_str("NaN")?;
+                return Ok(());
+            }
+        }
+
+        let mut raw = buf_sign;
+        raw.push_str(buf_int.as_str());
+        raw.push_str(buf_frac.as_str());
+        if !buf_exp.is_empty() {
+            raw.push('e');
+        }
+        raw.push_str(buf_exp_sign.as_str());
+        raw.push_str(buf_exp.as_str());
+
+        // undo the scaling
+        shift_point(&mut raw, -format.scale);
+
+        out.write_str(raw.as_str())?;
+
+        Ok(())
+    }
+
+    /// Rounds the raw number to the given number of fraction digits.
+    ///
+    /// The number must not have an exponent.
+    fn round_point(raw: &mut String, len_frac: usize, mode: RoundingMode) {
+        let (sign, num) = match raw.strip_prefix('-') {
+            Some(num) => ("-", num),
+            None => ("", raw.as_str()),
+        };
+        let (int, frac) = num.split_once('.').unwrap_or((num, ""));
+        if !int.bytes().chain(frac.bytes()).all(|c| c.is_ascii_digit()) {
+            return;
+        }
+
+        let mut digits = Vec::with_capacity(int.len() + len_frac + 1);
+        digits.extend_from_slice(int.as_bytes());
+        let (keep, rest) = frac.as_bytes().split_at(frac.len().min(len_frac));
+        digits.extend_from_slice(keep);
+        digits.resize(int.len() + len_frac, b'0');
+
+        let round_up = round_up(sign == "-", digits.last().copied(), rest, mode);
+        let mut len_int = int.len();
+        if round_up {
+            let mut carry = true;
+            for c in digits.iter_mut().rev() {
+                if *c == b'9' {
+                    *c = b'0';
+                } else {
+                    *c += 1;
+                    carry = false;
+                    break;
+                }
+            }
+            if carry {
+                digits.insert(0, b'1');
+                len_int += 1;
+            }
+        }
+
+        let mut rounded = String::with_capacity(digits.len() + 2);
+        rounded.push_str(sign);
+        if len_int == 0 {
+            rounded.push('0');
+        }
+        for (i, c) in digits.iter().enumerate() {
+            if i == len_int {
+                rounded.push('.');
+            }
+            rounded.push(*c as char);
+        }
+        *raw = rounded;
+    }
+
+    /// Rounds the raw number to the given number of significant digits.
+    ///
+    /// The number must not have an exponent.
+    fn round_significant(raw: &mut String, max_sig: u32, mode: RoundingMode) {
+        let num = raw.trim_start_matches('-');
+        let (int, frac) = num.split_once('.').unwrap_or((num, ""));
+        if !int.bytes().chain(frac.bytes()).all(|c| c.is_ascii_digit()) {
+            return;
+        }
+
+        // number of digits before the decimal point. negative for leading
+        // zeros after the decimal point.
+        let int = int.trim_start_matches('0');
+        let magnitude = if !int.is_empty() {
+            int.len() as i32
+        } else if let Some(zeros) = frac.bytes().position(|c| c != b'0') {
+            -(zeros as i32)
+        } else {
+            // zero
+            round_point(raw, 0, mode);
+            return;
+        };
+
+        let len_frac = max_sig as i32 - magnitude;
+        if len_frac >= 0 {
+            round_point(raw, len_frac as usize, mode);
+        } else {
+            shift_point(raw, len_frac);
+            round_point(raw, 0, mode);
+            shift_point(raw, -len_frac);
+        }
+    }
+
+    /// Removes trailing zeros from the fraction as long as there remain
+    /// min_sig significant digits. Adds zeros if there are not enough.
+    ///
+    /// If the number has an exponent this is done for the mantissa.
+    fn trim_significant(raw: &mut String, min_sig: u32) {
+        let idx_exp = memchr(b'e', raw.as_bytes()).unwrap_or(raw.len());
+        if !raw[..idx_exp]
+            .bytes()
+            .all(|c| c.is_ascii_digit() || c == b'.' || c == b'-')
+        {
+            return;
+        }
+        let exp = raw.split_off(idx_exp);
+
+        if raw.contains('.') {
+            while raw.ends_with('0') {
+                raw.pop();
+            }
+            if raw.ends_with('.') {
+                raw.pop();
+            }
+        }
+
+        let num = raw.trim_start_matches('-');
+        let digits = num.trim_start_matches(['0', '.']);
+        let n_sig = digits.bytes().filter(|c| c.is_ascii_digit()).count();
+        let n_sig = if digits.is_empty() { 1 } else { n_sig } as u32;
+        if n_sig < min_sig {
+            if !raw.contains('.') {
+                raw.push('.');
+            }
+            for _ in n_sig..min_sig {
+                raw.push('0');
+            }
+        }
+
+        raw.push_str(exp.as_str());
+    }
+
+    /// Rounds the raw number to a multiple of increment * 10^-scale.
+    ///
+    /// The number must not have an exponent. Works on the decimal digits
+    /// with a long division by the increment.
+    fn round_increment(raw: &mut String, increment: (u32, u32), mode: RoundingMode) {
+        let (increment, scale) = increment;
+        if increment == 0 {
+            return;
+        }
+
+        shift_point(raw, scale as i32);
+
+        let (sign, num) = match raw.strip_prefix('-') {
+            Some(num) => ("-", num),
+            None => ("", raw.as_str()),
+        };
+        let (int, frac) = num.split_once('.').unwrap_or((num, ""));
+        if !int.bytes().chain(frac.bytes()).all(|c| c.is_ascii_digit()) {
+            return;
+        }
+
+        // quotient and the digits of its fraction.
+        let m = increment as u64;
+        let mut rem = 0u64;
+        let mut quot = Vec::with_capacity(int.len() + 1);
+        for c in int.bytes() {
+            rem = rem * 10 + (c - b'0') as u64;
+            quot.push(b'0' + (rem / m) as u8);
+            rem %= m;
+        }
+        let mut rest = Vec::with_capacity(frac.len() + 2);
+        for c in frac.bytes().chain(Some(b'0')) {
+            rem = rem * 10 + (c - b'0') as u64;
+            rest.push(b'0' + (rem / m) as u8);
+            rem %= m;
+        }
+        if rem != 0 {
+            rest.push(b'1');
+        }
+
+        if round_up(sign == "-", quot.last().copied(), &rest, mode) {
+            let mut carry = true;
+            for c in quot.iter_mut().rev() {
+                if *c == b'9' {
+                    *c = b'0';
+                } else {
+                    *c += 1;
+                    carry = false;
+                    break;
+                }
+            }
+            if carry {
+                quot.insert(0, b'1');
+            }
+        }
+
+        // multiply again
+        let mut prod = Vec::with_capacity(quot.len() + 10);
+        let mut carry = 0u64;
+        for c in quot.iter().rev() {
+            let v = (c - b'0') as u64 * m + carry;
+            prod.push(b'0' + (v % 10) as u8);
+            carry = v / 10;
+        }
+        while carry > 0 {
+            prod.push(b'0' + (carry % 10) as u8);
+            carry /= 10;
+        }
+        while prod.len() > 1 && prod.last() == Some(&b'0') {
+            prod.pop();
+        }
+
+        let mut rounded = String::with_capacity(prod.len() + 1);
+        rounded.push_str(sign);
+        prod.iter().rev().for_each(|c| rounded.push(*c as char));
+        *raw = rounded;
+
+        shift_point(raw, -(scale as i32));
+    }
+
+    /// Rounds the mantissa of a raw number with exponent to the given
+    /// number of fraction digits, and to the increment if any.
+    fn round_exp(
+        raw: &mut String,
+        len_frac: usize,
+        increment: Option<(u32, u32)>,
+        mode: RoundingMode,
+    ) {
+        let Some(idx_exp) = memchr(b'e', raw.as_bytes()) else {
+            return;
+        };
+        let mut exp = raw[idx_exp + 1..].parse::<i32>().unwrap_or(0);
+
+        let mut mantissa = raw[..idx_exp].to_string();
+        if let Some(increment) = increment {
+            round_increment(&mut mantissa, increment, mode);
+        }
+        round_point(&mut mantissa, len_frac, mode);
+        // 9.99 -> 10.0
+        let num = mantissa.trim_start_matches('-');
+        if num.len() > 1 && !num.starts_with('0') && num.as_bytes()[1].is_ascii_digit() {
+            shift_point(&mut mantissa, -1);
+            round_point(&mut mantissa, len_frac, mode);
+            exp += 1;
+        }
+
+        raw.clear();
+        _ = write!(raw, "{}e{}", mantissa, exp);
+    }
+
+    /// Changes the exponent to a multiple of 3 and rounds the mantissa
+    /// to the given number of fraction digits or significant digits.
+    fn round_engineering(
+        raw: &mut String,
+        len_frac: usize,
+        max_sig: u32,
+        increment: Option<(u32, u32)>,
+        mode: RoundingMode,
+    ) {
+        let Some(idx_exp) = memchr(b'e', raw.as_bytes()) else {
+            return;
+        };
+        let exp = raw[idx_exp + 1..].parse::<i32>().unwrap_or(0);
+        let shift = exp.rem_euclid(3);
+        let mut exp = exp - shift;
+
+        let mut mantissa = raw[..idx_exp].to_string();
+        shift_point(&mut mantissa, shift);
+
+        let round = |mantissa: &mut String| {
+            if max_sig > 0 {
+                round_significant(mantissa, max_sig, mode);
+            } else {
+                if let Some(increment) = increment {
+                    round_increment(mantissa, increment, mode);
+                }
+                round_point(mantissa, len_frac, mode);
+            }
+        };
+        round(&mut mantissa);
+        // 999.99 -> 1000.0
+        let num = mantissa.trim_start_matches('-');
+        if num.split('.').next().unwrap_or_default().len() > 3 {
+            shift_point(&mut mantissa, -3);
+            round(&mut mantissa);
+            exp += 3;
+        }
+
+        raw.clear();
+        _ = write!(raw, "{}e{}", mantissa, exp);
+    }
+
+    /// Decides if the truncated digits require rounding up the last digit.
+    ///
+    /// Rounding up means away from zero.
+    fn round_up(negative: bool, last: Option<u8>, rest: &[u8], mode: RoundingMode) -> bool {
+        let is_zero = rest.iter().all(|c| *c == b'0');
+        let first = rest.first().copied().unwrap_or(b'0');
+        let is_tie = first == b'5' && rest[1..].iter().all(|c| *c == b'0');
 
-                    loop {
-                        match kt.next() {
-                            None => {
-                                break;
-                            }
-                            Some(s) => {
-                                let Some(c) = jt.next() else {
-                                    return Err(NumberFmtError::ParseInvalidCurrency);
-                                };
-                                if c != s {
-                                    return Err(NumberFmtError::ParseInvalidCurrency);
-                                }
-                            }
-                        }
-                    }
+        match mode {
+            RoundingMode::HalfUp => first >= b'5',
+            RoundingMode::HalfEven => {
+                if is_tie {
+                    last.map(|c| (c - b'0') % 2 == 1).unwrap_or(false)
+                } else {
+                    first >= b'5'
                 }
+            }
+            RoundingMode::HalfDown => first >= b'5' && !is_tie,
+            RoundingMode::Ceiling => !negative && !is_zero,
+            RoundingMode::Floor => negative && !is_zero,
+            RoundingMode::TowardZero => false,
+            RoundingMode::AwayFromZero => !is_zero,
+        }
+    }
 
-                Token::Separator(sep) => {
-                    if c == *sep {
-                        // ok
-                    } else {
-                        return Err(NumberFmtError::ParseInvalidSeparator);
-                    }
+    /// Multiplies the raw number by 10^shift by moving the decimal point.
+    ///
+    /// If the number has an exponent, only the exponent is changed.
+    fn shift_point(raw: &mut String, shift: i32) {
+        if shift == 0 {
+            return;
+        }
+
+        if let Some(idx_exp) = memchr(b'e', raw.as_bytes()) {
+            let exp = raw[idx_exp + 1..].parse::<i32>().unwrap_or(0);
+            raw.truncate(idx_exp + 1);
+            _ = write!(raw, "{}", exp + shift);
+            return;
+        }
+
+        let (sign, num) = match raw.strip_prefix('-') {
+            Some(num) => ("-", num),
+            None => ("", raw.as_str()),
+        };
+        let (int, frac) = num.split_once('.').unwrap_or((num, ""));
+        if int.is_empty() && frac.is_empty() {
+            return;
+        }
+        // NaN, inf
+        if !int.bytes().chain(frac.bytes()).all(|c| c.is_ascii_digit()) {
+            return;
+        }
+
+        let point = int.len() as i32 + shift;
+        let digits = int.bytes().chain(frac.bytes());
+        let len = (int.len() + frac.len()) as i32;
+
+        let mut shifted = String::new();
+        shifted.push_str(sign);
+        if point <= 0 {
+            shifted.push_str("0.");
+            for _ in point..0 {
+                shifted.push('0');
+            }
+            digits.for_each(|c| shifted.push(c as char));
+        } else if point >= len {
+            digits.for_each(|c| shifted.push(c as char));
+            for _ in len..point {
+                shifted.push('0');
+            }
+        } else {
+            for (i, c) in digits.enumerate() {
+                if i as i32 == point {
+                    shifted.push('.');
                 }
+                shifted.push(c as char);
             }
         }
 
-        out.write_str(buf_sign.as_str())?;
-        out.write_str(buf_int.as_str())?;
-        out.write_str(buf_frac.as_str())?;
-        if !buf_exp.is_empty() {
-            out.write_char('e')?;
-        }
-        out.write_str(buf_exp_sign.as_str())?;
-        out.write_str(buf_exp.as_str())?;
+        // leading zeros
+        let num = &shifted[sign.len()..];
+        let trim = num.len() - num.trim_start_matches('0').len();
+        let trim = if num[trim..].starts_with('.') || trim == num.len() {
+            trim.saturating_sub(1)
+        } else {
+            trim
+        };
+        shifted.replace_range(sign.len()..sign.len() + trim, "");
 
-        Ok(())
+        *raw = shifted;
+    }
+
+    /// Width of the sign text markers.
+    fn sign_txt_width(sym: &NumberSymbols) -> usize {
+        max(
+            sym.positive_txt.as_str().chars().count(),
+            sym.negative_txt.as_str().chars().count(),
+        )
+    }
+
+    /// Can this token be displayed as a blank?
+    fn can_be_blank(t: &Token) -> bool {
+        match t {
+            Token::Digit0(_, _, _)
+            | Token::DecimalSepAlways
+            | Token::PlusInt
+            | Token::PlusExp
+            | Token::Currency
+            | Token::Percent
+            | Token::PerMille
+            | Token::Significant(_) => false,
+            Token::Separator(c) => *c == ' ',
+            _ => true,
+        }
     }
 
     /// Takes a raw number string and applies the format.
@@ -1090,23 +5053,48 @@ pub mod core {
 
         // sign
         let disp_sign = if raw_sign.is_empty() {
-            sym.positive_sym
+            sym.positive_sym.as_str()
         } else {
-            sym.negative_sym
+            sym.negative_sym.as_str()
         };
 
-        // integer
+        // significant digits have a variable length.
+        let is_sig = format.max_sig > 0;
+
+        // integer. a single 0 needs no place in the pattern.
+        let raw_int = if !is_sig && format.len_int == 0 && raw_int == "0" {
+            ""
+        } else {
+            raw_int
+        };
         let int = raw_int.as_bytes();
         let len_int = int.len() as u32;
-        if len_int > format.len_int {
+        if !is_sig && len_int > format.len_int {
             return Err(NumberFmtError::FmtLenInt);
         }
 
+        // minimum grouping. if the leftmost group is too short, the
+        // separators are left out and added as padding at the left.
+        let mut pad_group = 0;
+        let mut skip_min_group = false;
+        if sym.min_grouping > 1 && !skip_group {
+            if let Some(first_grp) = first_grp(format, sym) {
+                if len_int < first_grp + sym.min_grouping as u32 {
+                    skip_min_group = true;
+                    pad_group = format
+                        .tok
+                        .iter()
+                        .filter(|t| matches!(t, Token::GroupingSep(x, _) if *x < len_int))
+                        .count();
+                }
+            }
+        }
+
         // dec-sep
         let disp_decimal_sep = if !raw_frac.is_empty() || format.has_frac_0 {
-            sym.decimal_sep
+            sym.decimal_sep.as_str()
         } else {
-            ' '
+            " "
         };
 
         // fraction
@@ -1132,9 +5120,9 @@ pub mod core {
                 ' '
             };
             let disp_exp_sign = if raw_exp_sign.is_empty() {
-                sym.positive_sym
+                sym.positive_sym.as_str()
             } else {
-                sym.negative_sym
+                sym.negative_sym.as_str()
             };
 
             if len_exp > format.len_exp {
@@ -1156,17 +5144,35 @@ pub mod core {
                 shift_exp_pos,
             )
         } else {
-            (' ', ' ', ' ', 0, 0)
+            (' ', ' ', " ", 0, 0)
         };
 
+        // general notation with the fixed layout.
+        let blank_exp = !EXP && format.general.is_some();
+
         let mut used_sign = false;
         let mut used_exp_sign = false;
 
         for m in format.tok.iter() {
+            if pad_group > 0
+                && matches!(
+                    m,
+                    Token::Digit0(Mode::Integer, _, _)
+                        | Token::Digit(Mode::Integer, _, _)
+                        | Token::Numeric(Mode::Integer, _, _)
+                        | Token::GroupingSep(_, _)
+                )
+            {
+                for _ in 0..pad_group {
+                    out.write_char(' ')?;
+                }
+                pad_group = 0;
+            }
+
             match m {
                 Token::SignInt => {
                     debug_assert!(!used_sign);
-                    out.write_char(disp_sign)?;
+                    out.write_str(disp_sign)?;
                     used_sign = true;
                 }
                 Token::PlusInt => {
@@ -1177,67 +5183,142 @@ pub mod core {
                         out.write_char('-')?;
                     }
                 }
+                Token::SignTxt => {
+                    debug_assert!(!used_sign);
+                    let txt = if raw_sign.is_empty() {
+                        sym.positive_txt.as_str()
+                    } else {
+                        sym.negative_txt.as_str()
+                    };
+                    out.write_str(txt)?;
+                    for _ in txt.chars().count()..sign_txt_width(sym) {
+                        out.write_char(' ')?;
+                    }
+                    used_sign = true;
+                }
+                Token::Significant(can_be_sign) => {
+                    if *can_be_sign && !format.implied_sign {
+                        debug_assert!(!used_sign);
+                        if !raw_sign.is_empty() {
+                            out.write_str(sym.negative_sym.as_str())?;
+                        } else if sym.positive_sym.as_str() != " " {
+                            out.write_str(sym.positive_sym.as_str())?;
+                        }
+                        used_sign = true;
+                    }
+                    for (i, c) in int.iter().enumerate() {
+                        let idx = int.len() - i;
+                        if format.sig_grp
+                            && !skip_group
+                            && !skip_min_group
+                            && i > 0
+                            && sym.is_grouping_pos(idx as u32)
+                        {
+                            out.write_char(disp_decimal_grp)?;
+                        }
+                        out.write_char(sym.map_digit(*c))?;
+                    }
+                    if !frac.is_empty() {
+                        out.write_str(sym.decimal_sep.as_str())?;
+                        for c in frac {
+                            out.write_char(sym.map_digit(*c))?;
+                        }
+                    }
+                }
+                Token::ParenOpen => {
+                    debug_assert!(!used_sign);
+                    if raw_sign.is_empty() {
+                        out.write_char(' ')?;
+                    } else {
+                        out.write_char('(')?;
+                    }
+                    used_sign = true;
+                }
+                Token::ParenClose => {
+                    if raw_sign.is_empty() {
+                        out.write_char(' ')?;
+                    } else {
+                        out.write_char(')')?;
+                    }
+                }
                 Token::GroupingSep(i, can_be_sign) => {
                     if skip_group {
                         // noop
+                    } else if skip_min_group && len_int > *i {
+                        // padding at the left
                     } else if len_int > *i {
                         out.write_char(disp_decimal_grp)?;
-                    } else if *can_be_sign && max(len_int, format.min_int_sign) == *i {
+                    } else if *can_be_sign
+                        && !format.implied_sign
+                        && max(len_int, format.min_int_sign) == *i
+                    {
                         debug_assert!(!used_sign);
-                        out.write_char(disp_sign)?;
+                        out.write_str(disp_sign)?;
                         used_sign = true;
                     } else {
                         out.write_char(' ')?;
                     }
                 }
-                Token::Digit0(Mode::Integer, i) => {
+                Token::Digit0(Mode::Integer, i, _) => {
                     if len_int > *i {
-                        out.write_char(int[(len_int - i - 1) as usize] as char)?;
+                        out.write_char(sym.map_digit(int[(len_int - i - 1) as usize]))?;
                     } else {
-                        out.write_char('0')?;
+                        out.write_char(sym.map_digit(b'0'))?;
                     }
                 }
-                Token::Digit(Mode::Integer, i) => {
+                Token::Digit(Mode::Integer, i, _) => {
                     if len_int > *i {
-                        out.write_char(int[(len_int - i - 1) as usize] as char)?;
+                        out.write_char(sym.map_digit(int[(len_int - i - 1) as usize]))?;
                     } else {
                         out.write_char(' ')?;
                     }
                 }
                 Token::Numeric(Mode::Integer, i, can_be_sign) => {
                     if len_int > *i {
-                        out.write_char(int[(len_int - i - 1) as usize] as char)?;
-                    } else if *can_be_sign && max(len_int, format.min_int_sign) == *i {
+                        out.write_char(sym.map_digit(int[(len_int - i - 1) as usize]))?;
+                    } else if *can_be_sign
+                        && !format.implied_sign
+                        && max(len_int, format.min_int_sign) == *i
+                    {
                         debug_assert!(!used_sign);
-                        out.write_char(disp_sign)?;
+                        out.write_str(disp_sign)?;
                         used_sign = true;
                     } else {
                         out.write_char(' ')?;
                     }
                 }
                 Token::DecimalSep => {
-                    out.write_char(disp_decimal_sep)?;
+                    out.write_str(disp_decimal_sep)?;
                 }
                 Token::DecimalSepAlways => {
-                    out.write_char(sym.decimal_sep)?;
+                    out.write_str(sym.decimal_sep.as_str())?;
+                }
+                Token::FractionGrp(i) => {
+                    if let Some(fraction_grp) = sym.fraction_grp {
+                        if len_frac > *i {
+                            out.write_char(fraction_grp)?;
+                        } else {
+                            out.write_char(' ')?;
+                        }
+                    }
                 }
-                Token::Digit0(Mode::Fraction, i) => {
+                Token::Digit0(Mode::Fraction, i, _) => {
                     if len_frac > *i {
-                        out.write_char(frac[*i as usize] as char)?;
+                        out.write_char(sym.map_digit(frac[*i as usize]))?;
                     } else {
-                        out.write_char('0')?;
+                        out.write_char(sym.map_digit(b'0'))?;
                     }
                 }
-                Token::Digit(Mode::Fraction, i) => {
+                Token::Digit(Mode::Fraction, i, _) => {
                     if len_frac > *i {
-                        out.write_char(frac[*i as usize] as char)?;
+                        out.write_char(sym.map_digit(frac[*i as usize]))?;
                     } else {
                         out.write_char(' ')?;
                     }
                 }
                 Token::Numeric(Mode::Fraction, i, _) => {
                     if len_frac > *i {
-                        out.write_char(frac[*i as usize] as char)?;
+                        out.write_char(sym.map_digit(frac[*i as usize]))?;
                     } else {
                         out.write_char(' ')?;
                     }
@@ -1245,23 +5326,29 @@ pub mod core {
                 Token::ExponentUpper => {
                     if EXP {
                         out.write_char(disp_exp_upper)?;
+                    } else if blank_exp {
+                        out.write_char(' ')?;
                     }
                 }
                 Token::ExponentLower => {
                     if EXP {
                         out.write_char(disp_exp_lower)?;
+                    } else if blank_exp {
+                        out.write_char(' ')?;
                     }
                 }
                 Token::SignExp => {
                     if EXP {
                         debug_assert!(!used_exp_sign);
-                        if raw_exp_sign.is_empty() && sym.positive_sym == ' ' {
+                        if raw_exp_sign.is_empty() && sym.positive_sym.as_str() == " " {
                             // explicit sign in the exponent shows '+'.
                             out.write_char('+')?;
                         } else {
-                            out.write_char(disp_exp_sign)?;
+                            out.write_str(disp_exp_sign)?;
                         }
                         used_exp_sign = true;
+                    } else if blank_exp {
+                        out.write_char(' ')?;
                     }
                 }
                 Token::PlusExp => {
@@ -1273,16 +5360,18 @@ pub mod core {
                             out.write_char('-')?;
                         }
                         used_exp_sign = true;
+                    } else if blank_exp {
+                        out.write_char(' ')?;
                     }
                 }
-                Token::Digit0(Mode::Exponent, i) => {
+                Token::Digit0(Mode::Exponent, i, _) => {
                     if EXP {
                         if *i >= shift_exp_pos {
                             // left-shift exponent
                         } else if len_exp > *i {
-                            out.write_char(exp[(len_exp - i - 1) as usize] as char)?;
+                            out.write_char(sym.map_digit(exp[(len_exp - i - 1) as usize]))?;
                         } else {
-                            out.write_char('0')?;
+                            out.write_char(sym.map_digit(b'0'))?;
                         }
                         // append shifted digits as blank
                         if *i == 0 {
@@ -1290,14 +5379,16 @@ pub mod core {
                                 out.write_char(' ')?;
                             }
                         }
+                    } else if blank_exp {
+                        out.write_char(' ')?;
                     }
                 }
-                Token::Digit(Mode::Exponent, i) => {
+                Token::Digit(Mode::Exponent, i, _) => {
                     if EXP {
                         if *i >= shift_exp_pos {
                             // left-shift exponent
                         } else if len_exp > *i {
-                            out.write_char(exp[(len_exp - i - 1) as usize] as char)?;
+                            out.write_char(sym.map_digit(exp[(len_exp - i - 1) as usize]))?;
                         } else {
                             out.write_char(' ')?;
                         }
@@ -1307,6 +5398,8 @@ pub mod core {
                                 out.write_char(' ')?;
                             }
                         }
+                    } else if blank_exp {
+                        out.write_char(' ')?;
                     }
                 }
                 Token::Numeric(Mode::Exponent, i, can_be_sign) => {
@@ -1314,10 +5407,10 @@ pub mod core {
                         if *i >= shift_exp_pos {
                             // left-shift exponent
                         } else if len_exp > *i {
-                            out.write_char(exp[(len_exp - i - 1) as usize] as char)?;
+                            out.write_char(sym.map_digit(exp[(len_exp - i - 1) as usize]))?;
                         } else if *can_be_sign && max(len_exp, format.min_exp_sign) == *i {
                             debug_assert!(!used_exp_sign);
-                            out.write_char(disp_exp_sign)?;
+                            out.write_str(disp_exp_sign)?;
                             used_exp_sign = true;
                         } else {
                             out.write_char(' ')?;
@@ -1329,18 +5422,26 @@ pub mod core {
                                 out.write_char(' ')?;
                             }
                         }
+                    } else if blank_exp {
+                        out.write_char(' ')?;
                     }
                 }
                 Token::Currency => {
                     out.write_str(sym.currency_sym.as_str())?;
                 }
+                Token::Percent => {
+                    out.write_char(sym.percent_sym)?;
+                }
+                Token::PerMille => {
+                    out.write_char(sym.permille_sym)?;
+                }
                 Token::Separator(v) => {
                     out.write_char(*v)?;
                 }
             }
         }
 
-        if !used_sign && !raw_sign.is_empty() {
+        if !used_sign && !raw_sign.is_empty() && !format.implied_sign {
             return Err(NumberFmtError::FmtNoSign);
         }
         if !used_exp_sign && !raw_exp_sign.is_empty() {
@@ -1363,27 +5464,282 @@ pub mod core {
 
         let mut raw = RAW.take();
 
+        let res = format_raw(&number, format, sym, &mut raw, out);
+        RAW.set(raw);
+        res
+    }
+
+    /// Chooses the section and maps the number.
+    fn format_raw<W: FmtWrite, Number: LowerExp + Display>(
+        number: &Number,
+        format: &NumberFormat,
+        sym: &NumberSymbols,
+        raw: &mut String,
+        out: &mut W,
+    ) -> Result<(), NumberFmtError> {
+        let mut has_exp = write_raw(number, format, raw)?;
+
+        let (section, kind) = select_section(raw, format);
+        if kind != Section::Positive {
+            has_exp = write_raw(number, section, raw)?;
+        }
+        let raw = match kind {
+            Section::Zero => raw.trim_start_matches('-'),
+            Section::NaN => "",
+            _ => raw.as_str(),
+        };
+
+        let (raw_sign, raw_num) = match raw.strip_prefix('-') {
+            Some(num) => (true, num),
+            None => (false, raw),
+        };
+        match raw_num {
+            "NaN" => return map_special(false, sym.nan_sym.as_str(), section, has_exp, sym, out),
+            "inf" => {
+                let inf = sym.infinity_sym.as_str();
+                return map_special(raw_sign, inf, section, has_exp, sym, out);
+            }
+            _ => {}
+        }
+
+        match section.overflow {
+            Overflow::Error => map_num_as(raw, section, has_exp, sym, out),
+            overflow => {
+                // a missing sign is found at the end, so the output is buffered.
+                let mut buf = String::new();
+                match map_num_as(raw, section, has_exp, sym, &mut buf) {
+                    Err(NumberFmtError::FmtLenInt | NumberFmtError::FmtNoSign) => match overflow {
+                        Overflow::Fill(c) => map_fill(section, has_exp, sym, c, out),
+                        _ => map_expand(raw, section, has_exp, sym, out),
+                    },
+                    res => {
+                        res?;
+                        out.write_str(buf.as_str())?;
+                        Ok(())
+                    }
+                }
+            }
+        }
+    }
+
+    /// Maps the number with or without exponent.
+    fn map_num_as<W: FmtWrite>(
+        raw: &str,
+        format: &NumberFormat,
+        has_exp: bool,
+        sym: &NumberSymbols,
+        out: &mut W,
+    ) -> Result<(), NumberFmtError> {
+        if has_exp {
+            map_num::<_, true>(raw, format, sym, out)
+        } else {
+            map_num::<_, false>(raw, format, sym, out)
+        }
+    }
+
+    /// Maps the number with a pattern expanded to the necessary
+    /// integer digits. Adds a "#" if the sign needs a place.
+    fn map_expand<W: FmtWrite>(
+        raw: &str,
+        format: &NumberFormat,
+        has_exp: bool,
+        sym: &NumberSymbols,
+        out: &mut W,
+    ) -> Result<(), NumberFmtError> {
+        let (_, raw_int, _, _, _) = split_num(raw);
+        let n = (raw_int.len() as u32).saturating_sub(format.len_int);
+
+        let mut buf = String::new();
+        let expanded = format.expand(n, false)?;
+        match map_num_as(raw, &expanded, has_exp, sym, &mut buf) {
+            Err(NumberFmtError::FmtNoSign) => {
+                buf.clear();
+                let expanded = format.expand(n, true)?;
+                map_num_as(raw, &expanded, has_exp, sym, &mut buf)?;
+            }
+            res => res?,
+        }
+        out.write_str(buf.as_str())?;
+        Ok(())
+    }
+
+    /// Fills the width of the pattern with the char.
+    fn map_fill<W: FmtWrite>(
+        format: &NumberFormat,
+        has_exp: bool,
+        sym: &NumberSymbols,
+        fill: char,
+        out: &mut W,
+    ) -> Result<(), NumberFmtError> {
+        for _ in 0..pattern_width(format, has_exp, sym)? {
+            out.write_char(fill)?;
+        }
+        Ok(())
+    }
+
+    /// Writes NaN or infinity right-aligned within the width of the pattern.
+    fn map_special<W: FmtWrite>(
+        negative: bool,
+        txt: &str,
+        format: &NumberFormat,
+        has_exp: bool,
+        sym: &NumberSymbols,
+        out: &mut W,
+    ) -> Result<(), NumberFmtError> {
+        let len_sign = if negative {
+            sym.negative_sym.as_str().chars().count()
+        } else {
+            0
+        };
+        let len = txt.chars().count() + len_sign;
+        for _ in len..pattern_width(format, has_exp, sym)? {
+            out.write_char(' ')?;
+        }
+        if negative {
+            out.write_str(sym.negative_sym.as_str())?;
+        }
+        out.write_str(txt)?;
+        Ok(())
+    }
+
+    /// Width of the output of the pattern.
+    fn pattern_width(
+        format: &NumberFormat,
+        has_exp: bool,
+        sym: &NumberSymbols,
+    ) -> Result<usize, NumberFmtError> {
+        let mut buf = String::new();
+        map_num_as("0", format, has_exp, sym, &mut buf)?;
+        Ok(buf.chars().count())
+    }
+
+    /// Write the number with the std-format required by the format.
+    ///
+    /// Returns true if the exponent layout is used.
+    fn write_raw<Number: LowerExp + Display>(
+        number: &Number,
+        format: &NumberFormat,
+        raw: &mut String,
+    ) -> Result<bool, NumberFmtError> {
+        write_raw_as(number, format, format.has_exp, raw)?;
+        if format.has_exp {
+            if let Some((min_exp, max_exp)) = format.general {
+                let exp = raw
+                    .split_once('e')
+                    .and_then(|(_, exp)| exp.parse::<i32>().ok());
+                if let Some(exp) = exp {
+                    if min_exp <= exp && exp < max_exp {
+                        write_raw_as(number, format, false, raw)?;
+                        return Ok(false);
+                    }
+                }
+            }
+        }
+        Ok(format.has_exp)
+    }
+
+    /// Write the number with or without exponent.
+    fn write_raw_as<Number: LowerExp + Display>(
+        number: &Number,
+        format: &NumberFormat,
+        has_exp: bool,
+        raw: &mut String,
+    ) -> Result<(), NumberFmtError> {
         raw.clear();
-        let res = if format.has_exp {
+        if has_exp && format.engineering {
+            let rounding = format.rounding.unwrap_or(RoundingMode::HalfEven);
+            write!(raw, "{:e}", number).map_err(|_| NumberFmtError::Fmt)?;
+            shift_point(raw, format.scale);
+            round_engineering(
+                raw,
+                format.len_frac as usize,
+                format.max_sig,
+                format.increment,
+                rounding,
+            );
+            if format.max_sig > 0 {
+                trim_significant(raw, format.min_sig);
+            }
+            return Ok(());
+        }
+        if format.max_sig > 0 {
+            let rounding = format.rounding.unwrap_or(RoundingMode::HalfEven);
+            if has_exp {
+                write!(raw, "{:e}", number).map_err(|_| NumberFmtError::Fmt)?;
+                shift_point(raw, format.scale);
+                round_exp(raw, format.max_sig as usize - 1, None, rounding);
+            } else {
+                write!(raw, "{}", number).map_err(|_| NumberFmtError::Fmt)?;
+                shift_point(raw, format.scale);
+                round_significant(raw, format.max_sig, rounding);
+            }
+            trim_significant(raw, format.min_sig);
+            return Ok(());
+        }
+        if format.rounding.is_some() || format.increment.is_some() {
+            let rounding = format.rounding.unwrap_or(RoundingMode::HalfEven);
+            // start with all the decimal digits.
+            if has_exp {
+                write!(raw, "{:e}", number).map_err(|_| NumberFmtError::Fmt)?;
+                shift_point(raw, format.scale);
+                round_exp(raw, format.len_frac as usize, format.increment, rounding);
+            } else {
+                write!(raw, "{}", number).map_err(|_| NumberFmtError::Fmt)?;
+                shift_point(raw, format.scale);
+                if let Some(increment) = format.increment {
+                    round_increment(raw, increment, rounding);
+                }
+                round_point(raw, format.len_frac as usize, rounding);
+            }
+            return Ok(());
+        }
+
+        if has_exp {
             write!(raw, "{:.*e}", format.len_frac as usize, number)
                 .map_err(|_| NumberFmtError::Fmt)?;
-            map_num::<_, true>(raw.as_str(), format, sym, out)
         } else {
-            write!(raw, "{:.*}", format.len_frac as usize, number)
-                .map_err(|_| NumberFmtError::Fmt)?;
-            map_num::<_, false>(raw.as_str(), format, sym, out)
-        };
+            // the scale needs some extra precision.
+            let prec = format.len_frac as i32 + format.scale;
+            if prec >= 0 {
+                write!(raw, "{:.*}", prec as usize, number).map_err(|_| NumberFmtError::Fmt)?;
+            } else {
+                // rounding must be done after shifting.
+                write!(raw, "{}", number).map_err(|_| NumberFmtError::Fmt)?;
+            }
+            shift_point(raw, format.scale);
+            if prec < 0 {
+                round_point(raw, format.len_frac as usize, RoundingMode::HalfEven);
+            }
+            return Ok(());
+        }
+        shift_point(raw, format.scale);
+        Ok(())
+    }
 
-        match res {
-            Ok(v) => {
-                RAW.set(raw);
-                Ok(v)
+    /// Select the section for a raw number.
+    ///
+    /// Zero is any number that is rounded to all zero digits.
+    fn select_section<'a>(raw: &str, format: &'a NumberFormat) -> (&'a NumberFormat, Section) {
+        if raw == "NaN" {
+            if let Some(nan) = &format.nan {
+                return (nan, Section::NaN);
             }
-            Err(e) => {
-                RAW.set(raw);
-                Err(e)
+        } else {
+            let mantissa = raw.split('e').next().unwrap_or_default();
+            let is_zero = mantissa.bytes().any(|c| c.is_ascii_digit())
+                && mantissa.bytes().all(|c| matches!(c, b'0' | b'.' | b'-'));
+            if is_zero {
+                if let Some(zero) = &format.zero {
+                    return (zero, Section::Zero);
+                }
+            }
+            if raw.starts_with('-') {
+                if let Some(neg) = &format.neg {
+                    return (neg, Section::Negative);
+                }
             }
         }
+        (format, Section::Positive)
     }
 
     /// Parse the number according to the exact format.
@@ -1413,6 +5769,138 @@ pub mod core {
         }
     }
 
+    /// Parse the number according to the exact format, and split it into
+    /// its parts. Stops before `FromStr`, so there is no loss of precision.
+    ///
+    /// buf is cleared and used for the raw number, the parts borrow
+    /// from it.
+    pub fn unmap_parts<'a>(
+        s: &str,
+        fmt: &NumberFormat,
+        sym: &NumberSymbols,
+        buf: &'a mut String,
+    ) -> Result<NumberParts<'a>, NumberFmtError> {
+        buf.clear();
+        unmap_num(s, fmt, sym, buf)?;
+        NumberParts::from_raw(buf)
+    }
+
+    /// Parse the number only using the symbols for translation, and split
+    /// it into its parts. See [clean_num()].
+    ///
+    /// buf is cleared and used for the raw number, the parts borrow
+    /// from it.
+    pub fn clean_parts<'a>(
+        s: &str,
+        sym: &NumberSymbols,
+        buf: &'a mut String,
+    ) -> Result<NumberParts<'a>, NumberFmtError> {
+        buf.clear();
+        clean_num(s, sym, buf)?;
+        NumberParts::from_raw(buf)
+    }
+
+    /// Parse an integer according to the exact format.
+    ///
+    /// The number is normalized first, an all-zero fraction is dropped
+    /// and an exponent is expanded. So "12.00" and "1.2e3" can be parsed
+    /// into an integer type.
+    ///
+    /// Fails with [NumberFmtError::ParseFraction] if a non-zero fraction
+    /// remains, and with [NumberFmtError::ParseOverflow] if the number
+    /// doesn't fit the type. Invalid input fails like with `parse_fmt`.
+    pub fn parse_fmt_int<F: FromStr>(
+        s: &str,
+        fmt: &NumberFormat,
+        sym: &NumberSymbols,
+    ) -> Result<F, NumberFmtError> {
+        thread_local! {
+            static RAW: Cell<String> = const {Cell::new(String::new())};
+        }
+
+        let mut raw = RAW.take();
+
+        raw.clear();
+        let res = unmap_num(s, fmt, sym, &mut raw)
+            .and_then(|_| int_raw(&mut raw))
+            .and_then(|_| raw.parse::<F>().map_err(|_| int_err::<F>()));
+        RAW.set(raw);
+        res
+    }
+
+    /// Error for a valid integer that doesn't parse. It is out of range,
+    /// unless F is no integer type at all.
+    fn int_err<F: FromStr>() -> NumberFmtError {
+        if "0".parse::<F>().is_ok() {
+            NumberFmtError::ParseOverflow
+        } else {
+            NumberFmtError::Parse
+        }
+    }
+
+    /// Normalizes the raw number to integer digits.
+    fn int_raw(raw: &mut String) -> Result<(), NumberFmtError> {
+        // the largest integer type has 39 digits.
+        const MAX_DIGITS: i64 = 40;
+
+        let (sign, num) = match raw.strip_prefix('-') {
+            Some(num) => ("-", num),
+            None => ("", raw.strip_prefix('+').unwrap_or(raw.as_str())),
+        };
+        let (num, exp) = num.split_once('e').unwrap_or((num, "0"));
+        let (int, frac) = num.split_once('.').unwrap_or((num, ""));
+        if int.is_empty() && frac.is_empty()
+            || !int.bytes().chain(frac.bytes()).all(|c| c.is_ascii_digit())
+        {
+            return Err(NumberFmtError::Parse);
+        }
+        let exp_digits = exp.strip_prefix(['-', '+']).unwrap_or(exp);
+        if exp_digits.is_empty() || !exp_digits.bytes().all(|c| c.is_ascii_digit()) {
+            return Err(NumberFmtError::Parse);
+        }
+        // an exponent out of range for i64 saturates.
+        let exp = match exp.parse::<i64>() {
+            Ok(exp) => exp,
+            Err(_) if exp.starts_with('-') => i64::MIN,
+            Err(_) => i64::MAX,
+        };
+
+        let digits = int.bytes().chain(frac.bytes());
+        let len_zero = digits.clone().take_while(|c| *c == b'0').count() as i64;
+        let len = (int.len() + frac.len()) as i64;
+        if len_zero == len {
+            raw.clear();
+            raw.push('0');
+            return Ok(());
+        }
+
+        let point = (int.len() as i64).saturating_add(exp);
+        if point.saturating_sub(len_zero) > MAX_DIGITS {
+            return Err(NumberFmtError::ParseOverflow);
+        }
+        if digits
+            .clone()
+            .skip(max(point, 0) as usize)
+            .any(|c| c != b'0')
+        {
+            return Err(NumberFmtError::ParseFraction);
+        }
+
+        let mut norm = String::new();
+        norm.push_str(sign);
+        for c in digits
+            .skip(len_zero as usize)
+            .take((point - len_zero) as usize)
+        {
+            norm.push(c as char);
+        }
+        for _ in len..point {
+            norm.push('0');
+        }
+        *raw = norm;
+        Ok(())
+    }
+
     /// Parse the number only using the symbols for translation.
     /// Takes digits and some specials and ignores the rest.
     pub fn parse_sym<F: FromStr>(s: &str, sym: &NumberSymbols) -> Result<F, NumberFmtError> {
@@ -1467,7 +5955,7 @@ pub fn formats<Number: LowerExp + Display>(
     pattern: &str,
     sym: &NumberSymbols,
 ) -> Result<String, NumberFmtError> {
-    let format = NumberFormat::new(pattern)?;
+    let format = NumberFormat::news(pattern, *sym)?;
     let mut out = String::new();
     core::format_to(number, &format, sym, &mut out)?;
     Ok(out)
@@ -1480,7 +5968,7 @@ pub fn formats_to<W: FmtWrite, Number: LowerExp + Display>(
     sym: &NumberSymbols,
     out: &mut W,
 ) -> Result<(), NumberFmtError> {
-    let format = NumberFormat::new(pattern)?;
+    let format = NumberFormat::news(pattern, *sym)?;
     core::format_to(number, &format, sym, out)
 }
 
@@ -1512,6 +6000,12 @@ pub fn parse_fmt<F: FromStr>(s: &str, fmt: &NumberFormat) -> Result<F, NumberFmt
     core::parse_fmt(s, fmt, &fmt.sym)
 }
 
+/// Parse an integer using the NumberFormat.
+/// See [core::parse_fmt_int]
+pub fn parse_fmt_int<F: FromStr>(s: &str, fmt: &NumberFormat) -> Result<F, NumberFmtError> {
+    core::parse_fmt_int(s, fmt, &fmt.sym)
+}
+
 /// Parse using the NumberFormat.
 /// Parses the number after applying [core::unmap_num]
 pub fn parse_format<F: FromStr>(
@@ -1519,6 +6013,6 @@ pub fn parse_format<F: FromStr>(
     pattern: &str,
     sym: &NumberSymbols,
 ) -> Result<F, NumberFmtError> {
-    let format = NumberFormat::new(pattern)?;
+    let format = NumberFormat::news(pattern, *sym)?;
     core::parse_fmt(s, &format, sym)
 }
@@ -180,7 +180,7 @@ impl Timing {
     pub fn median(&self, name: &str) -> (f64, f64, f64) {
         let mut s = self.summed.get(name).expect("sample").clone();
         s.sort_by(|v, w| v.total_cmp(w));
-        let m0 = s.len() * 1 / 10;
+        let m0 = s.len() / 10;
         let m5 = s.len() / 2;
         let m9 = s.len() * 9 / 10;
 
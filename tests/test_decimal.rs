@@ -11,3 +11,32 @@ fn test_1() {
     let f: Decimal = f0.parse("     12.  ").expect("fine");
     dbg!(f);
 }
+
+#[test]
+fn test_rounding() {
+    use format_num_pattern::RoundingMode;
+    use rust_decimal_macros::dec;
+
+    let f0 = NumberFormat::new("###,##0.00")
+        .expect("fine")
+        .rounding(RoundingMode::HalfUp);
+    assert_eq!(f0.fmt(dec!(1002.675)), Ok("  1,002.68".to_string()));
+    assert_eq!(f0.fmt(dec!(-1002.675)), Ok(" -1,002.68".to_string()));
+}
+
+#[test]
+fn test_increment() {
+    use format_num_pattern::RoundingMode;
+    use rust_decimal_macros::dec;
+
+    let f0 = NumberFormat::new("###,##0.00")
+        .expect("fine")
+        .increment(5, 2)
+        .rounding(RoundingMode::HalfUp);
+    assert_eq!(f0.fmt(dec!(1002.675)), Ok("  1,002.70".to_string()));
+    assert_eq!(f0.fmt(dec!(1002.624)), Ok("  1,002.60".to_string()));
+    assert_eq!(
+        f0.fmt(dec!(79228162514264337593543950.324)),
+        Err(format_num_pattern::NumberFmtError::FmtLenInt)
+    );
+}
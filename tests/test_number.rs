@@ -1,5 +1,8 @@
 use format_num_pattern as number;
-use format_num_pattern::{DisplayNumber, NumberFmtError, NumberFormat, NumberSymbols};
+use format_num_pattern::{
+    Currency, CurrencyDisplay, CurrencySym, DisplayNumber, Money, NumberFmtError, NumberFormat,
+    NumberParts, NumberSymbols, Overflow, ShortSym,
+};
 use std::fmt;
 
 #[test]
@@ -165,14 +168,14 @@ fn test_parse() {
 #[test]
 fn test_currency() {
     let sym = NumberSymbols {
-        decimal_sep: ',',
+        decimal_sep: ShortSym::new(","),
         decimal_grp: Some('.'),
         currency_sym: "€".into(),
         ..Default::default()
     };
 
     let sym2 = NumberSymbols {
-        decimal_sep: ',',
+        decimal_sep: ShortSym::new(","),
         decimal_grp: Some('.'),
         currency_sym: "Rub".into(),
         ..Default::default()
@@ -201,3 +204,1131 @@ fn test_currency() {
         Err(NumberFmtError::ParseInvalidCurrency)
     );
 }
+
+#[test]
+fn test_sections() {
+    let fmt = NumberFormat::new("#,##0.00;00.00\\ \\N;\\-\\-;\\n\\/\\a").expect("x");
+    assert_eq!(fmt.fmt(1234.5), Ok("1,234.50".to_string()));
+    assert_eq!(fmt.fmt(-12.5), Ok("12.50 N".to_string()));
+    assert_eq!(fmt.fmt(0), Ok("--".to_string()));
+    assert_eq!(fmt.fmt(-0.001), Ok("--".to_string()));
+    assert_eq!(fmt.fmt(f64::NAN), Ok("n/a".to_string()));
+
+    assert_eq!(fmt.parse::<f64>("1,234.50"), Ok(1234.5));
+    assert_eq!(fmt.parse::<f64>("12.50 N"), Ok(-12.5));
+    assert_eq!(fmt.parse::<f64>("--"), Ok(0.0));
+    assert!(fmt.parse::<f64>("n/a").expect("nan").is_nan());
+    assert_eq!(
+        fmt.parse::<f64>("x"),
+        Err(NumberFmtError::ParseInvalidDigit)
+    );
+
+    // a section without digits is literal text.
+    let fmt = NumberFormat::new("#,##0.00;(#,##0.00);-").expect("x");
+    assert_eq!(fmt.fmt(0), Ok("-".to_string()));
+    assert_eq!(fmt.fmt(-12.5), Ok("(   12.50)".to_string()));
+    assert_eq!(fmt.parse::<f64>("-"), Ok(0.0));
+
+    // the negative section alone shows the sign.
+    let fmt = NumberFormat::new("#,##0.00;#,##0.00\\ \\D\\B").expect("x");
+    assert_eq!(fmt.fmt(-5), Ok("    5.00 DB".to_string()));
+    assert_eq!(fmt.fmt(5), Ok("    5.00".to_string()));
+    assert_eq!(fmt.parse::<f64>("    5.00 DB"), Ok(-5.0));
+
+    // without a zero section
+    let fmt = NumberFormat::new("##0;###0-").expect("x");
+    assert_eq!(fmt.fmt(-12), Ok("  12-".to_string()));
+    assert_eq!(fmt.fmt(0), Ok("  0".to_string()));
+    assert_eq!(fmt.parse::<i32>("  12-"), Ok(-12));
+
+    assert_eq!(
+        NumberFormat::new("0;0;0;0;0"),
+        Err(NumberFmtError::ParseInvalidSection)
+    );
+
+    // a single section doesn't need to match completely.
+    let fmt = NumberFormat::new("0.00").expect("x");
+    assert_eq!(fmt.parse::<f64>("1.5"), Ok(1.5));
+    assert_eq!(fmt.parse::<f64>("1.50x"), Ok(1.5));
+    assert_eq!(fmt.parse::<f64>("1.509"), Ok(1.5));
+    let fmt = NumberFormat::new("0.00;-0.00").expect("x");
+    assert_eq!(
+        fmt.parse::<f64>("1.50x"),
+        Err(NumberFmtError::ParseTrailing)
+    );
+}
+
+#[test]
+fn test_paren() {
+    let fmt = NumberFormat::new("(#,##0.00)").expect("x");
+    assert_eq!(fmt.fmt(-1234.5), Ok("(1,234.50)".to_string()));
+    assert_eq!(fmt.fmt(-12), Ok("(   12.00)".to_string()));
+    assert_eq!(fmt.fmt(12), Ok("    12.00 ".to_string()));
+    assert_eq!(fmt.parse::<f64>("(1,234.50)"), Ok(-1234.5));
+    assert_eq!(fmt.parse::<f64>("    12.00 "), Ok(12.0));
+    assert_eq!(fmt.parse::<f64>("    12.00"), Ok(12.0));
+    assert_eq!(
+        fmt.parse::<f64>("(   12.00 "),
+        Err(NumberFmtError::ParseInvalidSign)
+    );
+
+    let fmt = NumberFormat::new("#,##0.00 ;(#,##0.00)").expect("x");
+    assert_eq!(fmt.fmt(-1234.5), Ok("(1,234.50)".to_string()));
+    assert_eq!(fmt.fmt(1234.5), Ok("1,234.50 ".to_string()));
+    assert_eq!(fmt.parse::<f64>("(1,234.50)"), Ok(-1234.5));
+
+    assert_eq!(
+        NumberFormat::new("(###"),
+        Err(NumberFmtError::ParseInvalidSign)
+    );
+    assert_eq!(
+        NumberFormat::new("-(###)"),
+        Err(NumberFmtError::ParseInvalidSign)
+    );
+}
+
+#[test]
+fn test_sign_txt() {
+    let fmt = NumberFormat::new("#,##0.00 S").expect("x");
+    assert_eq!(fmt.fmt(-1234.5), Ok("1,234.50 DB".to_string()));
+    assert_eq!(fmt.fmt(12), Ok("   12.00   ".to_string()));
+    assert_eq!(fmt.parse::<f64>("1,234.50 DB"), Ok(-1234.5));
+    assert_eq!(fmt.parse::<f64>("   12.00   "), Ok(12.0));
+    assert_eq!(
+        fmt.parse::<f64>("   12.00 XX"),
+        Err(NumberFmtError::ParseInvalidSign)
+    );
+
+    let sym = NumberSymbols {
+        positive_txt: "CR".into(),
+        ..Default::default()
+    };
+    let fmt = NumberFormat::news("#,##0.00 S", sym).expect("x");
+    assert_eq!(fmt.fmt(12), Ok("   12.00 CR".to_string()));
+    assert_eq!(fmt.parse::<f64>("   12.00 CR"), Ok(12.0));
+
+    let sym = NumberSymbols {
+        decimal_sep: ShortSym::new(","),
+        decimal_grp: Some('.'),
+        positive_txt: "Haben".into(),
+        negative_txt: "Soll".into(),
+        ..Default::default()
+    };
+    let fmt = NumberFormat::news("S #,##0.00", sym).expect("x");
+    assert_eq!(fmt.fmt(-1234.5), Ok("Soll  1.234,50".to_string()));
+    assert_eq!(fmt.fmt(12), Ok("Haben    12,00".to_string()));
+    assert_eq!(fmt.parse::<f64>("Soll  1.234,50"), Ok(-1234.5));
+    assert_eq!(fmt.parse::<f64>("Haben    12,00"), Ok(12.0));
+
+    assert_eq!(
+        NumberFormat::new("S###S"),
+        Err(NumberFmtError::ParseInvalidSign)
+    );
+}
+
+#[test]
+fn test_percent() {
+    let fmt = NumberFormat::new("##0.0%").expect("x");
+    assert_eq!(fmt.fmt(0.1234), Ok(" 12.3%".to_string()));
+    assert_eq!(fmt.fmt(-0.5), Ok("-50.0%".to_string()));
+    assert_eq!(fmt.fmt(1), Ok("100.0%".to_string()));
+    assert_eq!(fmt.parse::<f64>(" 12.3%"), Ok(0.123));
+    assert_eq!(fmt.parse::<f64>("-50.0%"), Ok(-0.5));
+    assert_eq!(
+        fmt.parse::<f64>(" 12.3 "),
+        Err(NumberFmtError::ParseInvalidSeparator)
+    );
+
+    let fmt = NumberFormat::new("#0 ‰").expect("x");
+    assert_eq!(fmt.fmt(0.012), Ok("12 ‰".to_string()));
+    assert_eq!(fmt.parse::<f64>("12 ‰"), Ok(0.012));
+
+    let fmt = NumberFormat::new("0.00e0%").expect("x");
+    assert_eq!(fmt.fmt(0.012), Ok("1.20e0%".to_string()));
+    assert_eq!(fmt.parse::<f64>("1.20e0%"), Ok(0.012));
+
+    let sym = NumberSymbols {
+        decimal_sep: ShortSym::new(","),
+        percent_sym: '٪',
+        ..Default::default()
+    };
+    let fmt = NumberFormat::news("##0.0%", sym).expect("x");
+    assert_eq!(fmt.fmt(0.1234), Ok(" 12,3٪".to_string()));
+    assert_eq!(fmt.parse::<f64>(" 12,3٪"), Ok(0.123));
+}
+
+#[test]
+fn test_thousands_scale() {
+    let fmt = NumberFormat::new("#,##0,").expect("x");
+    assert_eq!(fmt.fmt(1234567), Ok("1,235".to_string()));
+    assert_eq!(fmt.fmt(-1500), Ok("   -2".to_string()));
+    assert_eq!(fmt.fmt(2500), Ok("    2".to_string()));
+    assert_eq!(fmt.fmt(499.9), Ok("    0".to_string()));
+    assert_eq!(fmt.parse::<i32>("1,235"), Ok(1235000));
+
+    let fmt = NumberFormat::new("#,##0,,").expect("x");
+    assert_eq!(fmt.fmt(1234567890u64), Ok("1,235".to_string()));
+    assert_eq!(fmt.fmt(1499999.5), Ok("    1".to_string()));
+
+    let fmt = NumberFormat::new("#,##0.00,,").expect("x");
+    assert_eq!(fmt.fmt(1234567.0), Ok("    1.23".to_string()));
+    assert_eq!(fmt.fmt(999999999), Ok("1,000.00".to_string()));
+    assert_eq!(fmt.parse::<f64>("    1.23"), Ok(1230000.0));
+
+    let fmt = NumberFormat::new("#,##0,.0").expect("x");
+    assert_eq!(fmt.fmt(123456), Ok("  123.5".to_string()));
+    assert_eq!(fmt.fmt(0.12), Ok("    0.0".to_string()));
+}
+
+#[test]
+fn test_rounding() {
+    use format_num_pattern::RoundingMode;
+
+    let fmt = NumberFormat::new("#0.00").expect("x");
+    assert_eq!(fmt.fmt(2.675), Ok(" 2.67".to_string()));
+
+    let fmt = NumberFormat::new("#0.00")
+        .expect("x")
+        .rounding(RoundingMode::HalfUp);
+    assert_eq!(fmt.fmt(2.675), Ok(" 2.68".to_string()));
+    assert_eq!(fmt.fmt(-2.675), Ok("-2.68".to_string()));
+    assert_eq!(fmt.fmt(2.674), Ok(" 2.67".to_string()));
+    assert_eq!(fmt.fmt(9.999), Ok("10.00".to_string()));
+
+    let fmt = NumberFormat::new("#0.00")
+        .expect("x")
+        .rounding(RoundingMode::HalfEven);
+    assert_eq!(fmt.fmt(2.675), Ok(" 2.68".to_string()));
+    assert_eq!(fmt.fmt(2.665), Ok(" 2.66".to_string()));
+    assert_eq!(fmt.fmt(2.6651), Ok(" 2.67".to_string()));
+
+    let fmt = NumberFormat::new("#0.00")
+        .expect("x")
+        .rounding(RoundingMode::HalfDown);
+    assert_eq!(fmt.fmt(2.675), Ok(" 2.67".to_string()));
+    assert_eq!(fmt.fmt(2.6751), Ok(" 2.68".to_string()));
+
+    let fmt = NumberFormat::new("#0.00")
+        .expect("x")
+        .rounding(RoundingMode::Ceiling);
+    assert_eq!(fmt.fmt(2.671), Ok(" 2.68".to_string()));
+    assert_eq!(fmt.fmt(-2.679), Ok("-2.67".to_string()));
+
+    let fmt = NumberFormat::new("#0.00")
+        .expect("x")
+        .rounding(RoundingMode::Floor);
+    assert_eq!(fmt.fmt(2.679), Ok(" 2.67".to_string()));
+    assert_eq!(fmt.fmt(-2.671), Ok("-2.68".to_string()));
+
+    let fmt = NumberFormat::new("#0.00")
+        .expect("x")
+        .rounding(RoundingMode::TowardZero);
+    assert_eq!(fmt.fmt(2.679), Ok(" 2.67".to_string()));
+    assert_eq!(fmt.fmt(-2.679), Ok("-2.67".to_string()));
+
+    let fmt = NumberFormat::new("#0.00")
+        .expect("x")
+        .rounding(RoundingMode::AwayFromZero);
+    assert_eq!(fmt.fmt(2.671), Ok(" 2.68".to_string()));
+    assert_eq!(fmt.fmt(-2.671), Ok("-2.68".to_string()));
+    assert_eq!(fmt.fmt(2), Ok(" 2.00".to_string()));
+
+    let fmt = NumberFormat::new("0.00e00")
+        .expect("x")
+        .rounding(RoundingMode::HalfUp);
+    assert_eq!(fmt.fmt(2675), Ok("2.68e03".to_string()));
+    assert_eq!(fmt.fmt(9995), Ok("1.00e04".to_string()));
+
+    let fmt = NumberFormat::new("#,##0;(#,##0)")
+        .expect("x")
+        .rounding(RoundingMode::HalfUp);
+    assert_eq!(fmt.fmt(-2.5), Ok("(    3)".to_string()));
+}
+
+#[test]
+fn test_increment() {
+    use format_num_pattern::RoundingMode;
+
+    let fmt = NumberFormat::new("#,##0.05").expect("x");
+    assert_eq!(fmt.fmt(1.02), Ok("    1.00".to_string()));
+    assert_eq!(fmt.fmt(1.03), Ok("    1.05".to_string()));
+    assert_eq!(fmt.fmt(1.025), Ok("    1.00".to_string()));
+    assert_eq!(fmt.fmt(-1.03), Ok("   -1.05".to_string()));
+    assert_eq!(fmt.fmt(1234.99), Ok("1,235.00".to_string()));
+    assert_eq!(fmt.parse::<f64>("    1.05"), Ok(1.05));
+
+    let fmt = NumberFormat::new("#,##0.05")
+        .expect("x")
+        .rounding(RoundingMode::HalfUp);
+    assert_eq!(fmt.fmt(1.025), Ok("    1.05".to_string()));
+
+    let fmt = NumberFormat::new("#,#50").expect("x");
+    assert_eq!(fmt.fmt(1234), Ok("1,250".to_string()));
+    assert_eq!(fmt.fmt(1224), Ok("1,200".to_string()));
+    assert_eq!(fmt.fmt(24), Ok("    0".to_string()));
+    assert_eq!(fmt.fmt(30), Ok("   50".to_string()));
+    assert_eq!(fmt.fmt(-30), Ok("  -50".to_string()));
+    assert_eq!(fmt.parse::<f64>("   50"), Ok(50.0));
+    assert_eq!(fmt.to_string(), "#,#50");
+    // a "0" to the left pads with 0.
+    let fmt = NumberFormat::new("0050").expect("x");
+    assert_eq!(fmt.fmt(30), Ok("0050".to_string()));
+
+    let fmt = NumberFormat::new("##0.00")
+        .expect("x")
+        .increment(25, 2)
+        .rounding(RoundingMode::Ceiling);
+    assert_eq!(fmt.fmt(1.01), Ok("  1.25".to_string()));
+    assert_eq!(fmt.fmt(1.5), Ok("  1.50".to_string()));
+    assert_eq!(fmt.fmt(-1.4), Ok(" -1.25".to_string()));
+
+    // the mantissa is rounded.
+    let fmt = NumberFormat::new("0.05e0").expect("x");
+    assert_eq!(fmt.fmt(1.234), Ok("1.25e0".to_string()));
+    assert_eq!(fmt.fmt(9.99), Ok("1.00e1".to_string()));
+    let fmt = NumberFormat::new("0.00e0").expect("x").increment(5, 2);
+    assert_eq!(fmt.fmt(1.234), Ok("1.25e0".to_string()));
+    let fmt = NumberFormat::new("##0.0E0")
+        .expect("x")
+        .engineering()
+        .increment(5, 1);
+    assert_eq!(fmt.fmt(12345), Ok(" 12.5E3".to_string()));
+
+    assert_eq!(
+        NumberFormat::new("0e5"),
+        Err(NumberFmtError::ParseInvalidExp)
+    );
+}
+
+#[test]
+fn test_significant() {
+    let fmt = NumberFormat::new("@@@").expect("x");
+    assert_eq!(fmt.fmt(0.000123456), Ok("0.000123".to_string()));
+    assert_eq!(fmt.fmt(123456), Ok("123000".to_string()));
+    assert_eq!(fmt.fmt(-1.5), Ok("-1.50".to_string()));
+    assert_eq!(fmt.fmt(0), Ok("0.00".to_string()));
+    assert_eq!(fmt.fmt(99.96), Ok("100".to_string()));
+    assert_eq!(fmt.parse::<f64>("0.000123"), Ok(0.000123));
+    assert_eq!(fmt.parse::<f64>("-1.50"), Ok(-1.5));
+
+    let fmt = NumberFormat::new("@@##").expect("x");
+    assert_eq!(fmt.fmt(123456), Ok("123500".to_string()));
+    assert_eq!(fmt.fmt(1.2), Ok("1.2".to_string()));
+    assert_eq!(fmt.fmt(1), Ok("1.0".to_string()));
+    assert_eq!(fmt.fmt(0.012345), Ok("0.01234".to_string()));
+
+    let fmt = NumberFormat::new("#,##@@@ $").expect("x");
+    assert_eq!(fmt.fmt(1234567), Ok("1,230,000 $".to_string()));
+    assert_eq!(fmt.parse::<f64>("1,230,000 $"), Ok(1230000.0));
+
+    let fmt = NumberFormat::new("@@#e#0").expect("x");
+    assert_eq!(fmt.fmt(0.000123456), Ok("1.23e-4".to_string()));
+    assert_eq!(fmt.fmt(12), Ok("1.2e1 ".to_string()));
+    assert_eq!(fmt.parse::<f64>("1.23e-4"), Ok(0.000123));
+
+    let fmt = NumberFormat::new("(@@@)").expect("x");
+    assert_eq!(fmt.fmt(-1.5), Ok("(1.50)".to_string()));
+    assert_eq!(fmt.parse::<f64>("(1.50)"), Ok(-1.5));
+
+    assert_eq!(
+        NumberFormat::new("0@@"),
+        Err(NumberFmtError::ParseInvalidSignificant)
+    );
+    assert_eq!(
+        NumberFormat::new("@@.00"),
+        Err(NumberFmtError::ParseInvalidSignificant)
+    );
+    assert_eq!(
+        NumberFormat::new("@@ @@"),
+        Err(NumberFmtError::ParseInvalidSignificant)
+    );
+}
+
+#[test]
+fn test_general() {
+    let fmt = NumberFormat::new("@@##e#0")
+        .expect("x")
+        .general(-4, 6)
+        .expect("x");
+    assert_eq!(fmt.fmt(0.000123), Ok("0.000123   ".to_string()));
+    assert_eq!(fmt.fmt(123456), Ok("123500   ".to_string()));
+    assert_eq!(fmt.fmt(1.2e10), Ok("1.2e10".to_string()));
+    assert_eq!(fmt.fmt(1.2e-9), Ok("1.2e-9".to_string()));
+    assert_eq!(fmt.parse::<f64>("0.000123   "), Ok(0.000123));
+    assert_eq!(fmt.parse::<f64>("1.2e10"), Ok(1.2e10));
+
+    let fmt = NumberFormat::new("###0.00E#0")
+        .expect("x")
+        .general(-2, 4)
+        .expect("x");
+    assert_eq!(fmt.fmt(12.345), Ok("  12.35   ".to_string()));
+    assert_eq!(fmt.fmt(9999.9), Ok("   1.00E4 ".to_string()));
+    assert_eq!(fmt.fmt(999.4), Ok(" 999.40   ".to_string()));
+    assert_eq!(fmt.fmt(0.001234), Ok("   1.23E-3".to_string()));
+    assert_eq!(fmt.fmt(-0.5), Ok("  -0.50   ".to_string()));
+    assert_eq!(fmt.parse::<f64>("  12.35   "), Ok(12.35));
+    assert_eq!(fmt.parse::<f64>("   1.23E-3"), Ok(0.00123));
+
+    let fmt = NumberFormat::new("0.00E+00")
+        .expect("x")
+        .general(0, 1)
+        .expect("x");
+    assert_eq!(fmt.fmt(1.5), Ok("1.50    ".to_string()));
+    assert_eq!(fmt.fmt(15), Ok("1.50E+01".to_string()));
+    assert_eq!(fmt.parse::<f64>("1.50    "), Ok(1.5));
+
+    // no exponent in the pattern
+    assert_eq!(
+        NumberFormat::new("###0.00").expect("x").general(-2, 4),
+        Err(NumberFmtError::ParseInvalidExp)
+    );
+}
+
+#[test]
+fn test_engineering() {
+    let fmt = NumberFormat::new("##0.0E#0").expect("x").engineering();
+    assert_eq!(fmt.fmt(12345), Ok(" 12.3E3 ".to_string()));
+    assert_eq!(fmt.fmt(0.000456), Ok("456.0E-6".to_string()));
+    assert_eq!(fmt.fmt(1.5), Ok("  1.5E0 ".to_string()));
+    assert_eq!(fmt.fmt(-1500), Ok(" -1.5E3 ".to_string()));
+    assert_eq!(fmt.fmt(999.96), Ok("  1.0E3 ".to_string()));
+    assert_eq!(fmt.fmt(0), Ok("  0.0E0 ".to_string()));
+    assert_eq!(fmt.parse::<f64>(" 12.3E-3"), Ok(0.0123));
+    assert_eq!(fmt.parse::<f64>("456.0E-6"), Ok(0.000456));
+
+    let fmt = NumberFormat::new("##0E#0").expect("x").engineering();
+    assert_eq!(fmt.fmt(0.000456), Ok("456E-6".to_string()));
+    assert_eq!(fmt.fmt(4.56e10), Ok(" 46E9 ".to_string()));
+
+    let fmt = NumberFormat::new("@@@e#0").expect("x").engineering();
+    assert_eq!(fmt.fmt(12345), Ok("12.3e3 ".to_string()));
+    assert_eq!(fmt.fmt(1e-7), Ok("100e-9".to_string()));
+
+    let fmt = NumberFormat::new("#0.0E#0").expect("x").engineering();
+    assert_eq!(fmt.fmt(123456), Err(NumberFmtError::FmtLenInt));
+}
+
+#[test]
+fn test_overflow() {
+    let fmt = NumberFormat::new("#,##0.00").expect("x");
+    assert_eq!(fmt.fmt(1234567), Err(NumberFmtError::FmtLenInt));
+
+    let fmt = NumberFormat::new("#,##0.00")
+        .expect("x")
+        .overflow(Overflow::Expand);
+    assert_eq!(fmt.fmt(1234), Ok("1,234.00".to_string()));
+    assert_eq!(fmt.fmt(-123), Ok(" -123.00".to_string()));
+    assert_eq!(fmt.fmt(1234567), Ok("1,234,567.00".to_string()));
+    assert_eq!(fmt.fmt(-1234567), Ok("-1,234,567.00".to_string()));
+    assert_eq!(fmt.fmt(-234567), Ok("-234,567.00".to_string()));
+    assert_eq!(fmt.fmt(12345), Ok("12,345.00".to_string()));
+    assert_eq!(fmt.parse::<f64>("-1,234,567.00"), Ok(-1234567.0));
+    assert_eq!(fmt.parse::<f64>(" -123.00"), Ok(-123.0));
+
+    let fmt = NumberFormat::new("-000.0")
+        .expect("x")
+        .overflow(Overflow::Expand);
+    assert_eq!(fmt.fmt(-12345.6), Ok("-12345.6".to_string()));
+
+    // a "#" is added as place for the sign.
+    let fmt = NumberFormat::new("0.00")
+        .expect("x")
+        .overflow(Overflow::Expand);
+    assert_eq!(fmt.fmt(-12345), Ok("-12345.00".to_string()));
+    assert_eq!(fmt.fmt(-1), Ok("-1.00".to_string()));
+    assert_eq!(fmt.parse::<f64>("-12345.00"), Ok(-12345.0));
+    assert_eq!(fmt.parse::<f64>("-1.00"), Ok(-1.0));
+
+    let fmt = NumberFormat::new("#,##0.00;(#,##0.00)")
+        .expect("x")
+        .overflow(Overflow::Expand);
+    assert_eq!(fmt.fmt(-1234567), Ok("(1,234,567.00)".to_string()));
+    assert_eq!(fmt.parse::<f64>("(1,234,567.00)"), Ok(-1234567.0));
+
+    let fmt = NumberFormat::new("#,##0.00 $")
+        .expect("x")
+        .overflow(Overflow::Fill('#'));
+    assert_eq!(fmt.fmt(1234567), Ok("##########".to_string()));
+    assert_eq!(fmt.fmt(1234), Ok("1,234.00 $".to_string()));
+
+    let fmt = NumberFormat::new("#,##0.00")
+        .expect("x")
+        .overflow(Overflow::Fill('#'));
+    assert_eq!(fmt.fmt(-1234.5), Ok("########".to_string()));
+    assert_eq!(fmt.fmt(-234.5), Ok(" -234.50".to_string()));
+
+    let fmt = NumberFormat::new("0.0")
+        .expect("x")
+        .overflow(Overflow::Fill('*'));
+    assert_eq!(fmt.fmt(12), Ok("***".to_string()));
+}
+
+#[test]
+fn test_grouping_sizes() {
+    use format_num_pattern::Locale::{en_IN, en_US, hi_IN, unm_US, POSIX};
+
+    let sym = NumberSymbols::numeric(hi_IN);
+    assert_eq!(sym.grouping, Some((3, 2)));
+    let fmt = NumberFormat::news("#,##0", sym)
+        .expect("x")
+        .overflow(Overflow::Expand);
+    assert_eq!(fmt.fmt(123456789), Ok("12,34,56,789".to_string()));
+    assert_eq!(fmt.fmt(1234), Ok("1,234".to_string()));
+
+    let sym = NumberSymbols::numeric(en_IN);
+    assert_eq!(sym.grouping, Some((3, 2)));
+    let fmt = NumberFormat::news("######,##0.00", sym).expect("x");
+    assert_eq!(fmt.fmt(1234567), Ok("   12,34,567.00".to_string()));
+    assert_eq!(fmt.parse::<f64>("   12,34,567.00"), Ok(1234567.0));
+    assert_eq!(
+        number::formats(1234567, "######,##0", &sym),
+        Ok("   12,34,567".to_string())
+    );
+
+    let sym = NumberSymbols::monetary(hi_IN);
+    assert_eq!(sym.grouping, Some((3, 2)));
+
+    // more than one separator is used as written.
+    let sym = NumberSymbols::numeric(en_US);
+    assert_eq!(sym.grouping, Some((3, 3)));
+    let fmt = NumberFormat::news("#,##,##,##0", sym).expect("x");
+    assert_eq!(fmt.fmt(1234567), Ok("  12,34,567".to_string()));
+    let fmt = NumberFormat::news("######,##0", sym).expect("x");
+    assert_eq!(fmt.fmt(1234567), Ok("  1,234,567".to_string()));
+    let sym = NumberSymbols::numeric(en_IN);
+    let fmt = NumberFormat::news("###,###,##0", sym).expect("x");
+    assert_eq!(fmt.fmt(1234567), Ok("  1,234,567".to_string()));
+
+    // a group that differs from the primary size is used as written.
+    let sym = NumberSymbols::numeric(en_US);
+    let fmt = NumberFormat::news("####,##", sym).expect("x");
+    assert_eq!(fmt.fmt(123456), Ok("1234,56".to_string()));
+    assert_eq!(fmt.parse::<f64>("1234,56"), Ok(123456.0));
+    let sym = NumberSymbols::numeric(en_IN);
+    let fmt = NumberFormat::news("####,##", sym).expect("x");
+    assert_eq!(fmt.fmt(123456), Ok("1234,56".to_string()));
+
+    // glibc lists
+    assert_eq!(NumberSymbols::numeric(POSIX).grouping, None);
+    assert_eq!(NumberSymbols::numeric(unm_US).grouping, None);
+    let sym = NumberSymbols {
+        grouping: Some((3, 0)),
+        ..NumberSymbols::new()
+    };
+    let fmt = NumberFormat::news("#######,##0", sym).expect("x");
+    assert_eq!(fmt.fmt(1234567), Ok("   1234,567".to_string()));
+
+    let sym = NumberSymbols {
+        grouping: Some((4, 4)),
+        ..NumberSymbols::new()
+    };
+    let fmt = NumberFormat::news("#,##@@@", sym).expect("x");
+    assert_eq!(fmt.fmt(123456789), Ok("1,2300,0000".to_string()));
+
+    // without sizes the pattern is used as is.
+    let fmt = NumberFormat::new("##,##,##0").expect("x");
+    assert_eq!(fmt.fmt(1234567), Ok("12,34,567".to_string()));
+}
+
+#[test]
+fn test_min_grouping() {
+    let sym = NumberSymbols {
+        decimal_sep: ShortSym::new(","),
+        decimal_grp: Some(' '),
+        min_grouping: 2,
+        ..NumberSymbols::new()
+    };
+    let fmt = NumberFormat::news("###,##0.00", sym).expect("x");
+    assert_eq!(fmt.fmt(123), Ok("    123,00".to_string()));
+    assert_eq!(fmt.fmt(1234), Ok("   1234,00".to_string()));
+    assert_eq!(fmt.fmt(-1234), Ok("  -1234,00".to_string()));
+    assert_eq!(fmt.fmt(12345), Ok(" 12 345,00".to_string()));
+    assert_eq!(fmt.fmt(-12345), Ok("-12 345,00".to_string()));
+    assert_eq!(fmt.parse::<f64>("   1234,00"), Ok(1234.0));
+    assert_eq!(fmt.parse::<f64>("  -1234,00"), Ok(-1234.0));
+    assert_eq!(fmt.parse::<f64>("  1 234,00"), Ok(1234.0));
+    assert_eq!(fmt.parse::<f64>(" 12 345,00"), Ok(12345.0));
+    // a missing separator only where min_grouping leaves it out.
+    assert_eq!(
+        fmt.parse::<f64>("  12345,00"),
+        Err(NumberFmtError::ParseInvalidGroupingSep)
+    );
+    assert_eq!(
+        fmt.parse::<f64>(" 1 2345,00"),
+        Err(NumberFmtError::ParseInvalidGroupingSep)
+    );
+
+    let fmt = NumberFormat::news("$ #,###,##0", sym).expect("x");
+    assert_eq!(fmt.fmt(1234), Ok("$      1234".to_string()));
+    assert_eq!(fmt.fmt(1234567), Ok("$ 1 234 567".to_string()));
+    assert_eq!(fmt.parse::<f64>("$      1234"), Ok(1234.0));
+    assert_eq!(fmt.parse::<f64>("$ 1 234 567"), Ok(1234567.0));
+    assert_eq!(
+        fmt.parse::<f64>("$  1234567"),
+        Err(NumberFmtError::ParseInvalidGroupingSep)
+    );
+    assert_eq!(
+        fmt.parse::<f64>("$ 1234 567"),
+        Err(NumberFmtError::ParseInvalidGroupingSep)
+    );
+
+    let fmt = NumberFormat::news("#,##@@##", sym).expect("x");
+    assert_eq!(fmt.fmt(1234), Ok("1234".to_string()));
+    assert_eq!(fmt.fmt(12345), Ok("12 340".to_string()));
+}
+
+#[test]
+fn test_fraction_grouping() {
+    let fmt = NumberFormat::new("0.000,000,000").expect("x");
+    assert_eq!(
+        fmt.fmt(std::f64::consts::PI),
+        Ok("3.141 592 654".to_string())
+    );
+    assert_eq!(fmt.parse::<f64>("1.234 567 800"), Ok(1.2345678));
+    assert_eq!(fmt.to_string(), "0.000,000,000");
+
+    let fmt = NumberFormat::new("#,##0.000,9").expect("x");
+    assert_eq!(fmt.fmt(1234.5), Ok("1,234.500 0".to_string()));
+
+    let sym = NumberSymbols {
+        decimal_sep: ShortSym::new(","),
+        decimal_grp: Some('.'),
+        fraction_grp: Some('\u{202f}'),
+        ..NumberSymbols::new()
+    };
+    let fmt = NumberFormat::news("#,##0.00,00", sym).expect("x");
+    assert_eq!(fmt.fmt(1234.5678), Ok("1.234,56\u{202f}78".to_string()));
+    assert_eq!(fmt.parse::<f64>("1.234,56\u{202f}78"), Ok(1234.5678));
+
+    let sym = NumberSymbols {
+        fraction_grp: None,
+        ..NumberSymbols::new()
+    };
+    let fmt = NumberFormat::news("0.00,00", sym).expect("x");
+    assert_eq!(fmt.fmt(1.5), Ok("1.5000".to_string()));
+    assert_eq!(fmt.parse::<f64>("1.5000"), Ok(1.5));
+
+    // trailing separators still divide by 1000.
+    let fmt = NumberFormat::new("0.00,").expect("x");
+    assert_eq!(fmt.fmt(1500), Ok("1.50".to_string()));
+}
+
+#[test]
+fn test_native_digits() {
+    let sym = NumberSymbols {
+        decimal_sep: ShortSym::new("٫"),
+        decimal_grp: Some('٬'),
+        zero_digit: '٠',
+        ..NumberSymbols::new()
+    };
+    let fmt = NumberFormat::news("#,##0.00", sym).expect("x");
+    assert_eq!(fmt.fmt(1234.5), Ok("١٬٢٣٤٫٥٠".to_string()));
+    assert_eq!(fmt.fmt(-5), Ok("   -٥٫٠٠".to_string()));
+    assert_eq!(fmt.parse::<f64>("١٬٢٣٤٫٥٠"), Ok(1234.5));
+    assert_eq!(fmt.parse::<f64>("1٬234٫50"), Ok(1234.5));
+    assert_eq!(number::parse_sym::<f64>("١٬٢٣٤٫٥٠", &sym), Ok(1234.5));
+
+    let sym = NumberSymbols {
+        zero_digit: '०',
+        ..NumberSymbols::new()
+    };
+    let fmt = NumberFormat::news("0.0e00", sym).expect("x");
+    assert_eq!(fmt.fmt(12345), Ok("१.२e०४".to_string()));
+    assert_eq!(fmt.parse::<f64>("१.२e०४"), Ok(12000.0));
+
+    let sym = NumberSymbols {
+        zero_digit: '０',
+        ..NumberSymbols::new()
+    };
+    let fmt = NumberFormat::news("@@#", sym).expect("x");
+    assert_eq!(fmt.fmt(0.125), Ok("０.１２５".to_string()));
+    assert_eq!(fmt.parse::<f64>("０.１２５"), Ok(0.125));
+}
+
+#[test]
+fn test_nan_inf() {
+    let fmt = NumberFormat::new("#,##0.00").expect("x");
+    assert_eq!(fmt.fmt(f64::NAN), Ok("     NaN".to_string()));
+    assert_eq!(fmt.fmt(f64::INFINITY), Ok("       ∞".to_string()));
+    assert_eq!(fmt.fmt(f64::NEG_INFINITY), Ok("      -∞".to_string()));
+    assert!(fmt.parse::<f64>("     NaN").expect("x").is_nan());
+    assert_eq!(fmt.parse::<f64>("       ∞"), Ok(f64::INFINITY));
+    assert_eq!(fmt.parse::<f64>("      -∞"), Ok(f64::NEG_INFINITY));
+
+    let fmt = NumberFormat::new("0.00e00 $").expect("x");
+    assert_eq!(fmt.fmt(f64::INFINITY), Ok("        ∞".to_string()));
+
+    let sym = NumberSymbols {
+        nan_sym: ShortSym::new("n/a"),
+        infinity_sym: ShortSym::new("Infinity"),
+        ..NumberSymbols::new()
+    };
+    let fmt = NumberFormat::news("0.0", sym).expect("x");
+    assert_eq!(fmt.fmt(f64::NAN), Ok("n/a".to_string()));
+    assert_eq!(fmt.fmt(f64::NEG_INFINITY), Ok("-Infinity".to_string()));
+    assert_eq!(fmt.parse::<f64>("-Infinity"), Ok(f64::NEG_INFINITY));
+
+    // the NaN section wins.
+    let fmt = NumberFormat::new("0.00;-0.00;0;\\-").expect("x");
+    assert_eq!(fmt.fmt(f64::NAN), Ok("-".to_string()));
+    assert_eq!(fmt.fmt(f64::NEG_INFINITY), Ok("   -∞".to_string()));
+}
+
+#[test]
+fn test_multi_char_sym() {
+    let sym = NumberSymbols {
+        negative_sym: ShortSym::new("\u{200E}-"),
+        ..NumberSymbols::new()
+    };
+    let fmt = NumberFormat::news("#,##0.00", sym).expect("x");
+    assert_eq!(fmt.fmt(-12.5), Ok("  \u{200E}-12.50".to_string()));
+    assert_eq!(fmt.fmt(12.5), Ok("   12.50".to_string()));
+    assert_eq!(fmt.parse::<f64>("  \u{200E}-12.50"), Ok(-12.5));
+    assert_eq!(fmt.parse::<f64>("   12.50"), Ok(12.5));
+    assert_eq!(number::parse_sym::<f64>("\u{200E}-12.50", &sym), Ok(-12.5));
+
+    let fmt = NumberFormat::news("-0.00", sym).expect("x");
+    assert_eq!(fmt.fmt(-1.5), Ok("\u{200E}-1.50".to_string()));
+    assert_eq!(fmt.parse::<f64>("\u{200E}-1.50"), Ok(-1.5));
+
+    let fmt = NumberFormat::news("@@#", sym).expect("x");
+    assert_eq!(fmt.fmt(-1.5), Ok("\u{200E}-1.5".to_string()));
+    assert_eq!(fmt.parse::<f64>("\u{200E}-1.5"), Ok(-1.5));
+
+    let sym = NumberSymbols {
+        decimal_sep: ShortSym::new("\u{66b}\u{200F}"),
+        positive_sym: ShortSym::new("+"),
+        ..NumberSymbols::new()
+    };
+    let fmt = NumberFormat::news("-0.00", sym).expect("x");
+    assert_eq!(fmt.fmt(1.5), Ok("+1\u{66b}\u{200F}50".to_string()));
+    assert_eq!(fmt.fmt(-1.5), Ok("-1\u{66b}\u{200F}50".to_string()));
+    assert_eq!(fmt.parse::<f64>("+1\u{66b}\u{200F}50"), Ok(1.5));
+    assert_eq!(fmt.parse::<f64>("-1\u{66b}\u{200F}50"), Ok(-1.5));
+}
+
+#[test]
+fn test_currency_sym_len() {
+    use std::mem::size_of;
+
+    let sym = CurrencySym::new("Schweizer Franken");
+    assert_eq!(sym.as_str(), "Schweizer Franken");
+    let sym = CurrencySym::new(" руб. ");
+    assert_eq!(sym.as_str(), " руб. ");
+
+    // 38 bytes and a 2 byte char.
+    let long = "Schweizer Franken, Rappen und Batzen  ü";
+    assert_eq!(long.len(), 40);
+    let sym = CurrencySym::new(long);
+    assert_eq!(sym.as_str(), "Schweizer Franken, Rappen und Batzen  ");
+    assert_eq!(
+        CurrencySym::try_new(long),
+        Err(NumberFmtError::SymbolTooLong)
+    );
+    assert_eq!(CurrencySym::try_new("CHF").map(|v| v.len()), Ok(3));
+
+    // signs and separators are shorter.
+    assert_eq!(size_of::<ShortSym>(), 16);
+    let sym = ShortSym::new("Unendlichkeit ∞");
+    assert_eq!(sym.as_str(), "Unendlichkeit ");
+    assert_eq!(
+        ShortSym::try_new("Unendlichkeit ∞"),
+        Err(NumberFmtError::SymbolTooLong)
+    );
+
+    let sym = NumberSymbols {
+        currency_sym: CurrencySym::new(" Schweizer Franken"),
+        ..NumberSymbols::new()
+    };
+    let fmt = NumberFormat::news("#,##0.00$", sym).expect("x");
+    assert_eq!(fmt.fmt(12.5), Ok("   12.50 Schweizer Franken".to_string()));
+    assert_eq!(fmt.parse::<f64>("   12.50 Schweizer Franken"), Ok(12.5));
+}
+
+#[test]
+fn test_monetary_layout() {
+    use format_num_pattern::Locale::{de_AT_euro, de_DE_euro, en_US, nl_NL_euro};
+
+    let fmt = NumberFormat::monetary("0.00", NumberSymbols::monetary(de_AT_euro)).expect("x");
+    assert_eq!(fmt.fmt(1), Ok("€ 1,00".to_string()));
+    assert_eq!(fmt.fmt(-1), Ok("-€ 1,00".to_string()));
+    assert_eq!(fmt.parse::<f64>("-€ 1,00"), Ok(-1.0));
+
+    let fmt = NumberFormat::monetary("#,##0.00", NumberSymbols::monetary(de_DE_euro)).expect("x");
+    assert_eq!(fmt.fmt(1), Ok("    1,00 €".to_string()));
+    assert_eq!(fmt.fmt(-1), Ok("   -1,00 €".to_string()));
+    assert_eq!(fmt.parse::<f64>("   -1,00 €"), Ok(-1.0));
+
+    let fmt = NumberFormat::monetary("0.00", NumberSymbols::monetary(nl_NL_euro)).expect("x");
+    assert_eq!(fmt.fmt(-1), Ok("€ -1,00".to_string()));
+    assert_eq!(fmt.parse::<f64>("€ -1,00"), Ok(-1.0));
+
+    let fmt = NumberFormat::monetary("0.00", NumberSymbols::monetary(en_US)).expect("x");
+    assert_eq!(fmt.fmt(-1), Ok("-$1.00".to_string()));
+
+    let sym = NumberSymbols {
+        n_cs_precedes: false,
+        n_sep_by_space: 2,
+        n_sign_posn: 3,
+        ..NumberSymbols::monetary(de_DE_euro)
+    };
+    let fmt = NumberFormat::monetary("0.00", sym).expect("x");
+    assert_eq!(fmt.fmt(-1), Ok("1,00- €".to_string()));
+    assert_eq!(fmt.parse::<f64>("1,00- €"), Ok(-1.0));
+
+    let sym = NumberSymbols {
+        n_sign_posn: 0,
+        ..NumberSymbols::monetary(en_US)
+    };
+    let fmt = NumberFormat::monetary("0.00", sym).expect("x");
+    assert_eq!(fmt.fmt(-1), Ok("($1.00)".to_string()));
+    assert_eq!(fmt.fmt(1), Ok("$1.00".to_string()));
+
+    assert_eq!(
+        NumberFormat::monetary("$0.00", NumberSymbols::new()),
+        Err(NumberFmtError::ParseInvalidCurrency)
+    );
+    assert_eq!(
+        NumberFormat::monetary("-0.00", NumberSymbols::new()),
+        Err(NumberFmtError::ParseInvalidSign)
+    );
+}
+
+#[test]
+fn test_no_grouping_sep() {
+    let sym = NumberSymbols {
+        decimal_grp: None,
+        ..NumberSymbols::new()
+    };
+    let fmt = NumberFormat::news("###,##0.00", sym).expect("x");
+    assert_eq!(fmt.fmt(1234.5), Ok("  1234.50".to_string()));
+    assert_eq!(fmt.parse::<f64>("  1234.50"), Ok(1234.5));
+    assert_eq!(fmt.parse::<f64>("    -1.50"), Ok(-1.5));
+}
+
+#[test]
+fn test_currency_locale() {
+    use format_num_pattern::Locale::{ar_KW, de_DE_euro, ja_JP, POSIX};
+
+    let fmt = NumberFormat::currency(de_DE_euro).expect("x");
+    assert_eq!(fmt.fmt(-1234.5), Ok("-1.234,50 €".to_string()));
+    assert_eq!(fmt.parse::<f64>("-1.234,50 €"), Ok(-1234.5));
+
+    let fmt = NumberFormat::int_currency(de_DE_euro).expect("x");
+    assert_eq!(fmt.fmt(1234.5), Ok("1.234,50 EUR".to_string()));
+    assert_eq!(fmt.parse::<f64>("1.234,50 EUR"), Ok(1234.5));
+
+    let fmt = NumberFormat::currency(ja_JP).expect("x");
+    assert_eq!(fmt.fmt(1234.4), Ok("￥1,234".to_string()));
+    assert_eq!(fmt.parse::<f64>("￥1,234"), Ok(1234.0));
+
+    let fmt = NumberFormat::currency(ar_KW).expect("x");
+    assert_eq!(fmt.fmt(1.5), Ok("د.ك. 1.500".to_string()));
+
+    // no currency symbol and no grouping separator.
+    let fmt = NumberFormat::currency(POSIX).expect("x");
+    assert_eq!(fmt.fmt(-12.5), Ok("-12.50".to_string()));
+    assert_eq!(fmt.parse::<f64>("-12.50"), Ok(-12.5));
+}
+
+#[test]
+fn test_currency_table() {
+    use format_num_pattern::Locale::{de_AT_euro, en_US};
+
+    let usd = Currency::find("usd").expect("x");
+    assert_eq!(usd.code, "USD");
+    assert_eq!(usd.numeric, 840);
+    assert_eq!(usd.display(CurrencyDisplay::Name), "US Dollar");
+    assert_eq!(Currency::find("KWD").map(|v| v.minor_units), Some(3));
+    assert_eq!(Currency::find("JPY").map(|v| v.minor_units), Some(0));
+    assert_eq!(Currency::find("XXX").map(|v| v.numeric), Some(999));
+    assert_eq!(Currency::find("XAU").map(|v| v.name), Some("Gold"));
+    assert_eq!(Currency::find("CLF").map(|v| v.minor_units), Some(4));
+    assert_eq!(Currency::find("ABC"), None);
+    assert!(Currency::all().len() > 170);
+    assert!(Currency::all().windows(2).all(|v| v[0].code < v[1].code));
+    for c in Currency::all() {
+        for display in [
+            CurrencyDisplay::Code,
+            CurrencyDisplay::Symbol,
+            CurrencyDisplay::NarrowSymbol,
+            CurrencyDisplay::Name,
+        ] {
+            let txt = c.display(display);
+            assert_eq!(CurrencySym::try_new(txt).map(|v| v.len()), Ok(txt.len()));
+        }
+    }
+
+    let bam = Currency::find("BAM").expect("x");
+    let sym = NumberSymbols::monetary_with(en_US, bam, CurrencyDisplay::Name);
+    assert_eq!(
+        sym.currency_sym.as_str(),
+        "Bosnia-Herzegovina Convertible Mark"
+    );
+
+    let sym = NumberSymbols::monetary_with(de_AT_euro, usd, CurrencyDisplay::Symbol);
+    assert_eq!(sym.currency_sym.as_str(), "$");
+    assert_eq!(sym.decimal_sep.as_str(), ",");
+
+    let fmt = NumberFormat::currency_with(de_AT_euro, usd, CurrencyDisplay::Code).expect("x");
+    assert_eq!(fmt.fmt(-1234.5), Ok("-USD 1\u{202f}234,50".to_string()));
+    assert_eq!(fmt.parse::<f64>("-USD 1\u{202f}234,50"), Ok(-1234.5));
+
+    let jpy = Currency::find("JPY").expect("x");
+    let fmt = NumberFormat::currency_with(en_US, jpy, CurrencyDisplay::Symbol).expect("x");
+    assert_eq!(fmt.fmt(1234.4), Ok("¥1,234".to_string()));
+
+    let chf = Currency::find("CHF").expect("x");
+    let fmt = NumberFormat::currency_with(en_US, chf, CurrencyDisplay::Name)
+        .expect("x")
+        .increment(chf.cash_increment, chf.minor_units as u32);
+    assert_eq!(fmt.fmt(12.33), Ok("Swiss Franc12.35".to_string()));
+}
+
+#[test]
+fn test_money() {
+    use format_num_pattern::Locale::{de_AT_euro, en_US};
+
+    let usd = Currency::find("USD").expect("x");
+    let kwd = Currency::find("KWD").expect("x");
+
+    let fmt = NumberFormat::currency(de_AT_euro).expect("x");
+    let m = Money::new(-1234.5, usd);
+    assert_eq!(m.fmt(&fmt).to_string(), "-$ 1\u{202f}234,50");
+    assert_eq!(Money::<f64>::parse_fmt("-$ 1\u{202f}234,50", &fmt), Ok(m));
+
+    let m = Money::new(1.5, kwd);
+    assert_eq!(m.fmt(&fmt).to_string(), "KWD 1,500");
+    assert_eq!(Money::<f64>::parse_fmt("KWD 1,500", &fmt), Ok(m));
+
+    // int_currency shows the code.
+    let fmt = NumberFormat::int_currency(en_US).expect("x");
+    let m = Money::new(12.5, usd);
+    assert_eq!(m.fmt(&fmt).to_string(), "USD 12.50");
+    assert_eq!(Money::<f64>::parse_fmt("USD 12.50", &fmt), Ok(m));
+
+    // the narrow symbol is ambiguous.
+    let m = Money::<f64>::parse_fmt("kr 12.50", &fmt).expect("x");
+    assert_eq!(m.currency.code, "DKK");
+
+    assert_eq!(
+        Money::<f64>::parse_fmt("12.50", &fmt),
+        Err(NumberFmtError::ParseInvalidCurrency)
+    );
+
+    let sym = NumberSymbols::monetary(de_AT_euro);
+    let m = Money::new(12.5, usd);
+    assert_eq!(
+        m.format("$ #,##0.00", &sym).expect("x").to_string(),
+        "$    12,50"
+    );
+    assert_eq!(m.to_string(), "12.5");
+
+    // the pattern and the options of the format are kept.
+    let fmt = NumberFormat::news("#,##0.0 $", sym).expect("x");
+    let m = Money::new(1234.567, usd);
+    assert_eq!(m.fmt(&fmt).to_string(), "1\u{202f}234,57 $");
+    assert_eq!(
+        m.format("#,##0.0 $", &sym).expect("x").to_string(),
+        m.fmt(&fmt).to_string()
+    );
+    let jpy = Currency::find("JPY").expect("x");
+    let m = Money::new(1234.567, jpy);
+    assert_eq!(m.fmt(&fmt).to_string(), "1\u{202f}235 ¥");
+    assert_eq!(
+        Money::<f64>::parse_fmt("1\u{202f}235 ¥", &fmt),
+        Ok(Money::new(1235.0, jpy))
+    );
+
+    let fmt = NumberFormat::news("0.00 $;(0.00 $)", sym)
+        .expect("x")
+        .overflow(Overflow::Expand);
+    let m = Money::new(-1234567.891, kwd);
+    assert_eq!(m.fmt(&fmt).to_string(), "(1234567,891 KWD)");
+
+    let fmt = NumberFormat::news("0.00 $", sym)
+        .expect("x")
+        .increment(5, 2);
+    let m = Money::new(2.33, Currency::find("CHF").expect("x"));
+    assert_eq!(m.fmt(&fmt).to_string(), "2,35 CHF");
+
+    // integer amounts.
+    let fmt = NumberFormat::currency(en_US).expect("x");
+    let m = Money::new(12i64, usd);
+    assert_eq!(m.fmt(&fmt).to_string(), "$12.00");
+    assert_eq!(Money::<i64>::parse_fmt("$12.00", &fmt), Ok(m));
+    assert_eq!(
+        Money::<i64>::parse_fmt("$12.50", &fmt),
+        Err(NumberFmtError::ParseFraction)
+    );
+
+    // no currency in the format.
+    let fmt = NumberFormat::news("#,##0.00", sym).expect("x");
+    assert_eq!(
+        Money::new(1.5, usd).number_format(&fmt),
+        Err(NumberFmtError::FmtNoCurrency)
+    );
+    assert_eq!(
+        Money::new(1.5, usd).try_fmt(&fmt).err(),
+        Some(NumberFmtError::FmtNoCurrency)
+    );
+    assert_eq!(Money::new(1.5, usd).fmt(&fmt).to_string(), "    1,50");
+    let fmt = NumberFormat::news("#,##0.00 $", sym).expect("x");
+    assert_eq!(
+        Money::new(1.5, usd).try_fmt(&fmt).expect("x").to_string(),
+        "    1,50 $"
+    );
+    assert_eq!(
+        Money::new(1.5, usd).format("#,##0.00", &sym).err(),
+        Some(NumberFmtError::FmtNoCurrency)
+    );
+}
+
+#[test]
+fn test_minor_units() {
+    use format_num_pattern::RoundingMode;
+
+    let fmt = NumberFormat::new("#,##0.00").expect("x").minor_units(2);
+    assert_eq!(fmt.fmt(12345i64), Ok("  123.45".to_string()));
+    assert_eq!(fmt.fmt(-5i64), Ok("   -0.05".to_string()));
+    assert_eq!(fmt.fmt(0i64), Ok("    0.00".to_string()));
+    assert_eq!(fmt.parse::<i64>("  123.45"), Ok(12345));
+    assert_eq!(fmt.parse::<i64>("   -0.05"), Ok(-5));
+    assert_eq!(fmt.parse::<i64>("1,234.50"), Ok(123450));
+
+    // sets, doesn't add.
+    let fmt = NumberFormat::new("##0.00%")
+        .expect("x")
+        .minor_units(2)
+        .minor_units(4);
+    assert_eq!(fmt.fmt(1250u32), Ok(" 12.50%".to_string()));
+    assert_eq!(fmt.parse::<u32>(" 12.50%"), Ok(1250));
+
+    let fmt = NumberFormat::new("#########0.000000000000000000")
+        .expect("x")
+        .minor_units(18);
+    let v = 123_456_789_012_345_678_901_234_567u128;
+    assert_eq!(fmt.fmt(v), Ok(" 123456789.012345678901234567".to_string()));
+    assert_eq!(fmt.parse::<u128>(" 123456789.012345678901234567"), Ok(v));
+
+    let fmt = NumberFormat::new("##0.00;(##0.00)")
+        .expect("x")
+        .minor_units(2);
+    assert_eq!(fmt.fmt(-12345i128), Ok("(123.45)".to_string()));
+    assert_eq!(fmt.parse::<i128>("(123.45)"), Ok(-12345));
+
+    // less fraction digits are rounded.
+    let fmt = NumberFormat::new("##0.0").expect("x").minor_units(2);
+    assert_eq!(fmt.fmt(12345i64), Ok("123.4".to_string()));
+    let fmt = fmt.rounding(RoundingMode::HalfUp);
+    assert_eq!(fmt.fmt(12345i64), Ok("123.5".to_string()));
+}
+
+#[test]
+fn test_parse_int() {
+    let fmt = NumberFormat::new("#,##0.00").expect("x");
+    assert_eq!(fmt.parse_int::<i32>("   12.00"), Ok(12));
+    assert_eq!(fmt.parse_int::<i32>("   -3.00"), Ok(-3));
+    assert_eq!(fmt.parse_int::<u8>("   -0.00"), Ok(0));
+    assert_eq!(
+        fmt.parse_int::<i32>("   12.50"),
+        Err(NumberFmtError::ParseFraction)
+    );
+    assert_eq!(
+        fmt.parse_int::<u8>("1,234.00"),
+        Err(NumberFmtError::ParseOverflow)
+    );
+    assert_eq!(
+        fmt.parse_int::<u8>("   -3.00"),
+        Err(NumberFmtError::ParseOverflow)
+    );
+    assert_eq!(number::parse_fmt_int::<i64>("1,234.00", &fmt), Ok(1234));
+
+    let fmt = NumberFormat::new("0.0e#0").expect("x");
+    assert_eq!(fmt.parse_int::<i32>("1.2e 3"), Ok(1200));
+    assert_eq!(fmt.parse_int::<i32>("0.0e 5"), Ok(0));
+    assert_eq!(
+        fmt.parse_int::<i32>("1.2e-1"),
+        Err(NumberFmtError::ParseFraction)
+    );
+    assert_eq!(
+        fmt.parse_int::<u128>("1.0e99"),
+        Err(NumberFmtError::ParseOverflow)
+    );
+
+    // exponents out of range for i64.
+    let fmt = NumberFormat::new("0.0e#0000000000000000000").expect("x");
+    assert_eq!(
+        fmt.parse_int::<i64>("1.0e 99999999999999999999"),
+        Err(NumberFmtError::ParseOverflow)
+    );
+    assert_eq!(
+        fmt.parse_int::<i64>("1.0e-99999999999999999999"),
+        Err(NumberFmtError::ParseFraction)
+    );
+    assert_eq!(fmt.parse_int::<i64>("0.0e 99999999999999999999"), Ok(0));
+
+    // no integer type.
+    let fmt = NumberFormat::new("##0").expect("x");
+    assert_eq!(fmt.parse_int::<bool>("  1"), Err(NumberFmtError::Parse));
+    assert_eq!(
+        fmt.parse_int::<i32>("  x"),
+        Err(NumberFmtError::ParseInvalidDigit)
+    );
+
+    let fmt = NumberFormat::new("##0.00").expect("x").minor_units(2);
+    assert_eq!(fmt.parse_int::<i64>("123.45"), Ok(12345));
+}
+
+#[test]
+fn test_parse_parts() {
+    let mut buf = String::new();
+
+    let fmt = NumberFormat::new("#,##0.00e+00").expect("x");
+    let parts = fmt.parse_parts("1,234.50e-07", &mut buf).expect("x");
+    assert_eq!(
+        parts,
+        NumberParts {
+            negative: false,
+            int: "1234",
+            frac: "50",
+            exp_negative: true,
+            exp: "07",
+            nan: false,
+            infinite: false,
+        }
+    );
+
+    let fmt = NumberFormat::new("#,##0.000").expect("x");
+    let parts = fmt.parse_parts("  -12.500", &mut buf).expect("x");
+    assert!(parts.negative);
+    assert_eq!((parts.int, parts.frac, parts.exp), ("12", "500", ""));
+
+    let parts = fmt.parse_parts("      NaN", &mut buf).expect("x");
+    assert!(parts.nan);
+    let parts = fmt.parse_parts("        ∞", &mut buf).expect("x");
+    assert!(parts.infinite);
+
+    let sym = NumberSymbols {
+        decimal_sep: ShortSym::new(","),
+        decimal_grp: Some('.'),
+        ..NumberSymbols::new()
+    };
+    let parts = number::core::clean_parts("€ -1.234,50", &sym, &mut buf).expect("x");
+    assert!(parts.negative);
+    assert_eq!((parts.int, parts.frac), ("1234", "50"));
+
+    assert_eq!(
+        NumberParts::from_raw("12.3e+4"),
+        Ok(NumberParts {
+            int: "12",
+            frac: "3",
+            exp: "4",
+            ..Default::default()
+        })
+    );
+    assert_eq!(
+        NumberParts::from_raw("12x"),
+        Err(NumberFmtError::ParseInvalidDigit)
+    );
+}